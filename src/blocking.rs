@@ -0,0 +1,435 @@
+use alloy_primitives::{Address, B256};
+use chrono::{DateTime, Utc};
+use reqwest::ClientBuilder;
+use std::time::Duration;
+use tokio::runtime::{Builder, Runtime};
+
+use crate::{
+    client::{OpenSeaApiConfig, OpenSeaV2Client, RequestOptions},
+    types::{
+        api::{
+            events::{AssetEvent, GetEventsRequest, GetEventsResponse, SaleEvent},
+            nfts::{GetNftsRequest, GetNftsResponse, Nft},
+            orders::{ItemListing, Order, Price},
+            AccountResponse, CancelOrderRequest, CancelOrderResponse, CollectionResponse, CollectionStatsResponse, ContractResponse,
+            FulfillListingRequest, FulfillListingResponse, FulfillOfferRequest, GetAllListingsRequest, GetAllListingsResponse,
+            ListCollectionsRequest, ListCollectionsResponse, PartialResult, PostOrderRequest, PostOrderResponse, RetrieveListingsRequest,
+            RetrieveListingsResponse, RetrieveOffersRequest, RetrieveOffersResponse, TrendingPeriod,
+        },
+        Chain, OpenSeaApiError,
+    },
+};
+
+/// A blocking client for scripts and tools without an async runtime.
+///
+/// Each call runs the corresponding method of [`OpenSeaV2Client`] to completion on an internal single threaded
+/// runtime, so retries and caching behave the same. Like `reqwest::blocking` it must not be used from within an
+/// async runtime.
+#[derive(Debug)]
+pub struct OpenSeaV2ClientBlocking {
+    inner: OpenSeaV2Client,
+    runtime: Runtime,
+}
+
+impl OpenSeaV2ClientBlocking {
+    /// Create a new client with the given configuration.
+    pub fn new(cfg: OpenSeaApiConfig) -> Self {
+        Self::from_builder(cfg, ClientBuilder::new()).unwrap()
+    }
+
+    /// Create a client for mainnet using the given API key, see [`OpenSeaV2Client::with_api_key`].
+    pub fn with_api_key(api_key: impl Into<String>) -> Self {
+        Self::new(OpenSeaApiConfig { api_key: Some(api_key.into()), ..Default::default() })
+    }
+
+    /// Create a new client from a pre-configured `ClientBuilder`, see [`OpenSeaV2Client::from_builder`].
+    pub fn from_builder(cfg: OpenSeaApiConfig, builder: ClientBuilder) -> Result<Self, OpenSeaApiError> {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| OpenSeaApiError::Other(format!("Failed to create runtime: {e}")))?;
+        Ok(Self { inner: OpenSeaV2Client::from_builder(cfg, builder)?, runtime })
+    }
+
+    /// The async client the calls are delegated to.
+    pub fn inner(&self) -> &OpenSeaV2Client {
+        &self.inner
+    }
+
+    /// See [`OpenSeaV2Client::shutdown`]. Can be called from another thread to cancel a call waiting for a retry.
+    pub fn shutdown(&self) {
+        self.inner.shutdown()
+    }
+
+    /// See [`OpenSeaV2Client::get_collection_by_slug`].
+    pub fn get_collection_by_slug(&self, collection_slug: String) -> Result<CollectionResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_collection_by_slug(collection_slug))
+    }
+
+    /// See [`OpenSeaV2Client::retrieve_listings`].
+    pub fn retrieve_listings(&self, req: RetrieveListingsRequest) -> Result<RetrieveListingsResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.retrieve_listings(req))
+    }
+
+    /// See [`OpenSeaV2Client::retrieve_listings_on`].
+    pub fn retrieve_listings_on(&self, chain: Chain, req: RetrieveListingsRequest) -> Result<RetrieveListingsResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.retrieve_listings_on(chain, req))
+    }
+
+    /// See [`OpenSeaV2Client::retrieve_listings_opts`].
+    pub fn retrieve_listings_opts(
+        &self,
+        chain: Chain,
+        req: RetrieveListingsRequest,
+        opts: RequestOptions,
+    ) -> Result<RetrieveListingsResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.retrieve_listings_opts(chain, req, opts))
+    }
+
+    /// See [`OpenSeaV2Client::retrieve_all_listings`].
+    pub fn retrieve_all_listings(&self, req: RetrieveListingsRequest, max_pages: Option<usize>) -> Result<Vec<Order>, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.retrieve_all_listings(req, max_pages))
+    }
+
+    /// See [`OpenSeaV2Client::retrieve_all_listings_partial`].
+    pub fn retrieve_all_listings_partial(&self, req: RetrieveListingsRequest, max_pages: Option<usize>) -> PartialResult {
+        self.runtime.block_on(self.inner.retrieve_all_listings_partial(req, max_pages))
+    }
+
+    /// See [`OpenSeaV2Client::retrieve_listings_for_makers`].
+    pub fn retrieve_listings_for_makers(&self, makers: Vec<Address>, req: RetrieveListingsRequest) -> Result<Vec<Order>, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.retrieve_listings_for_makers(makers, req))
+    }
+
+    /// See [`OpenSeaV2Client::retrieve_offers`].
+    pub fn retrieve_offers(&self, req: RetrieveOffersRequest) -> Result<RetrieveOffersResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.retrieve_offers(req))
+    }
+
+    /// See [`OpenSeaV2Client::retrieve_offers_on`].
+    pub fn retrieve_offers_on(&self, chain: Chain, req: RetrieveOffersRequest) -> Result<RetrieveOffersResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.retrieve_offers_on(chain, req))
+    }
+
+    /// See [`OpenSeaV2Client::retrieve_offers_opts`].
+    pub fn retrieve_offers_opts(
+        &self,
+        chain: Chain,
+        req: RetrieveOffersRequest,
+        opts: RequestOptions,
+    ) -> Result<RetrieveOffersResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.retrieve_offers_opts(chain, req, opts))
+    }
+
+    /// See [`OpenSeaV2Client::get_account_offers`].
+    pub fn get_account_offers(
+        &self,
+        chain: Chain,
+        address: Address,
+        req: GetNftsRequest,
+    ) -> Result<RetrieveOffersResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_account_offers(chain, address, req))
+    }
+
+    /// See [`OpenSeaV2Client::retrieve_all_offers`].
+    pub fn retrieve_all_offers(&self, req: RetrieveOffersRequest, max_pages: Option<usize>) -> Result<Vec<Order>, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.retrieve_all_offers(req, max_pages))
+    }
+
+    /// See [`OpenSeaV2Client::fulfill_listing`].
+    pub fn fulfill_listing(&self, req: FulfillListingRequest) -> Result<FulfillListingResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.fulfill_listing(req))
+    }
+
+    /// See [`OpenSeaV2Client::fulfill_listing_opts`].
+    pub fn fulfill_listing_opts(
+        &self,
+        req: FulfillListingRequest,
+        opts: RequestOptions,
+    ) -> Result<FulfillListingResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.fulfill_listing_opts(req, opts))
+    }
+
+    /// See [`OpenSeaV2Client::fulfill_offer`].
+    pub fn fulfill_offer(&self, req: FulfillOfferRequest) -> Result<FulfillListingResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.fulfill_offer(req))
+    }
+
+    /// See [`OpenSeaV2Client::fulfill_offer_opts`].
+    pub fn fulfill_offer_opts(&self, req: FulfillOfferRequest, opts: RequestOptions) -> Result<FulfillListingResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.fulfill_offer_opts(req, opts))
+    }
+
+    /// See [`OpenSeaV2Client::post_listing`].
+    pub fn post_listing(&self, chain: Chain, req: PostOrderRequest) -> Result<PostOrderResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.post_listing(chain, req))
+    }
+
+    /// See [`OpenSeaV2Client::post_listing_opts`].
+    pub fn post_listing_opts(
+        &self,
+        chain: Chain,
+        req: PostOrderRequest,
+        opts: RequestOptions,
+    ) -> Result<PostOrderResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.post_listing_opts(chain, req, opts))
+    }
+
+    /// See [`OpenSeaV2Client::post_offer`].
+    pub fn post_offer(&self, chain: Chain, req: PostOrderRequest) -> Result<PostOrderResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.post_offer(chain, req))
+    }
+
+    /// See [`OpenSeaV2Client::post_offer_opts`].
+    pub fn post_offer_opts(&self, chain: Chain, req: PostOrderRequest, opts: RequestOptions) -> Result<PostOrderResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.post_offer_opts(chain, req, opts))
+    }
+
+    /// See [`OpenSeaV2Client::get_order`].
+    pub fn get_order(&self, chain: Chain, protocol_address: Address, order_hash: B256) -> Result<Order, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_order(chain, protocol_address, order_hash))
+    }
+
+    /// See [`OpenSeaV2Client::cancel_order`].
+    pub fn cancel_order(
+        &self,
+        chain: Chain,
+        protocol_address: Address,
+        order_hash: B256,
+        req: CancelOrderRequest,
+    ) -> Result<CancelOrderResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.cancel_order(chain, protocol_address, order_hash, req))
+    }
+
+    /// See [`OpenSeaV2Client::cancel_order_opts`].
+    pub fn cancel_order_opts(
+        &self,
+        chain: Chain,
+        protocol_address: Address,
+        order_hash: B256,
+        req: CancelOrderRequest,
+        opts: RequestOptions,
+    ) -> Result<CancelOrderResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.cancel_order_opts(chain, protocol_address, order_hash, req, opts))
+    }
+
+    /// See [`OpenSeaV2Client::cancel_orders`].
+    pub fn cancel_orders(
+        &self,
+        chain: Chain,
+        protocol_address: Address,
+        order_hashes: Vec<B256>,
+        signatures: Vec<String>,
+    ) -> Vec<Result<(), OpenSeaApiError>> {
+        self.runtime.block_on(self.inner.cancel_orders(chain, protocol_address, order_hashes, signatures))
+    }
+
+    /// See [`OpenSeaV2Client::get_collection`].
+    pub fn get_collection(&self, collection_slug: String) -> Result<CollectionResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_collection(collection_slug))
+    }
+
+    /// See [`OpenSeaV2Client::get_collection_opts`].
+    pub fn get_collection_opts(&self, collection_slug: String, opts: RequestOptions) -> Result<CollectionResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_collection_opts(collection_slug, opts))
+    }
+
+    /// See [`OpenSeaV2Client::get_collections`].
+    pub fn get_collections(&self, req: ListCollectionsRequest) -> Result<ListCollectionsResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_collections(req))
+    }
+
+    /// See [`OpenSeaV2Client::get_trending_collections`].
+    pub fn get_trending_collections(&self, chain: Chain, period: TrendingPeriod) -> Result<ListCollectionsResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_trending_collections(chain, period))
+    }
+
+    /// See [`OpenSeaV2Client::get_collections_by_owner`].
+    pub fn get_collections_by_owner(
+        &self,
+        owner: Address,
+        req: ListCollectionsRequest,
+    ) -> Result<ListCollectionsResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_collections_by_owner(owner, req))
+    }
+
+    /// See [`OpenSeaV2Client::get_collection_stats`].
+    pub fn get_collection_stats(&self, collection_slug: String) -> Result<CollectionStatsResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_collection_stats(collection_slug))
+    }
+
+    /// See [`OpenSeaV2Client::get_collection_stats_opts`].
+    pub fn get_collection_stats_opts(
+        &self,
+        collection_slug: String,
+        opts: RequestOptions,
+    ) -> Result<CollectionStatsResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_collection_stats_opts(collection_slug, opts))
+    }
+
+    /// See [`OpenSeaV2Client::get_floor_price`].
+    pub fn get_floor_price(&self, collection_slug: String) -> Result<Option<Price>, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_floor_price(collection_slug))
+    }
+
+    /// See [`OpenSeaV2Client::get_collection_stats_batch`].
+    pub fn get_collection_stats_batch(
+        &self,
+        slugs: Vec<String>,
+        concurrency: usize,
+    ) -> Vec<(String, Result<CollectionStatsResponse, OpenSeaApiError>)> {
+        self.runtime.block_on(self.inner.get_collection_stats_batch(slugs, concurrency))
+    }
+
+    /// See [`OpenSeaV2Client::get_all_listings`].
+    pub fn get_all_listings(
+        &self,
+        collection_slug: String,
+        params: GetAllListingsRequest,
+    ) -> Result<GetAllListingsResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_all_listings(collection_slug, params))
+    }
+
+    /// See [`OpenSeaV2Client::get_all_listings_opts`].
+    pub fn get_all_listings_opts(
+        &self,
+        collection_slug: String,
+        params: GetAllListingsRequest,
+        opts: RequestOptions,
+    ) -> Result<GetAllListingsResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_all_listings_opts(collection_slug, params, opts))
+    }
+
+    /// See [`OpenSeaV2Client::get_best_listing`].
+    pub fn get_best_listing(&self, collection_slug: String, identifier: String) -> Result<Option<ItemListing>, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_best_listing(collection_slug, identifier))
+    }
+
+    /// See [`OpenSeaV2Client::get_best_listing_opts`].
+    pub fn get_best_listing_opts(
+        &self,
+        collection_slug: String,
+        identifier: String,
+        opts: RequestOptions,
+    ) -> Result<Option<ItemListing>, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_best_listing_opts(collection_slug, identifier, opts))
+    }
+
+    /// See [`OpenSeaV2Client::get_best_listings`].
+    pub fn get_best_listings(
+        &self,
+        collection_slug: String,
+        identifiers: Vec<String>,
+        concurrency: usize,
+    ) -> Vec<(String, Result<Option<ItemListing>, OpenSeaApiError>)> {
+        self.runtime.block_on(self.inner.get_best_listings(collection_slug, identifiers, concurrency))
+    }
+
+    /// See [`OpenSeaV2Client::get_collection_events`].
+    pub fn get_collection_events(&self, collection_slug: String, req: GetEventsRequest) -> Result<GetEventsResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_collection_events(collection_slug, req))
+    }
+
+    /// See [`OpenSeaV2Client::get_collection_events_opts`].
+    pub fn get_collection_events_opts(
+        &self,
+        collection_slug: String,
+        req: GetEventsRequest,
+        opts: RequestOptions,
+    ) -> Result<GetEventsResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_collection_events_opts(collection_slug, req, opts))
+    }
+
+    /// See [`OpenSeaV2Client::get_sales`].
+    pub fn get_sales(
+        &self,
+        collection_slug: String,
+        after: Option<DateTime<Utc>>,
+        limit: Option<u8>,
+    ) -> Result<Vec<SaleEvent>, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_sales(collection_slug, after, limit))
+    }
+
+    /// See [`OpenSeaV2Client::wait_for_fill`].
+    pub fn wait_for_fill(
+        &self,
+        collection_slug: String,
+        order_hash: String,
+        timeout: Duration,
+        poll_interval: Duration,
+    ) -> Result<Option<AssetEvent>, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.wait_for_fill(collection_slug, order_hash, timeout, poll_interval))
+    }
+
+    /// See [`OpenSeaV2Client::get_contract`].
+    pub fn get_contract(&self, chain: Chain, address: Address) -> Result<ContractResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_contract(chain, address))
+    }
+
+    /// See [`OpenSeaV2Client::get_contract_opts`].
+    pub fn get_contract_opts(&self, chain: Chain, address: Address, opts: RequestOptions) -> Result<ContractResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_contract_opts(chain, address, opts))
+    }
+
+    /// See [`OpenSeaV2Client::collection_for_contract`].
+    pub fn collection_for_contract(&self, chain: Chain, address: Address) -> Result<CollectionResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.collection_for_contract(chain, address))
+    }
+
+    /// See [`OpenSeaV2Client::get_account`].
+    pub fn get_account(&self, address_or_username: String) -> Result<AccountResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_account(address_or_username))
+    }
+
+    /// See [`OpenSeaV2Client::get_account_opts`].
+    pub fn get_account_opts(&self, address_or_username: String, opts: RequestOptions) -> Result<AccountResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_account_opts(address_or_username, opts))
+    }
+
+    /// See [`OpenSeaV2Client::resolve_address`].
+    pub fn resolve_address(&self, username: String) -> Result<Address, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.resolve_address(username))
+    }
+
+    /// See [`OpenSeaV2Client::get_nfts_by_contract`].
+    pub fn get_nfts_by_contract(&self, chain: Chain, contract: Address, req: GetNftsRequest) -> Result<GetNftsResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_nfts_by_contract(chain, contract, req))
+    }
+
+    /// See [`OpenSeaV2Client::get_nfts_by_contract_opts`].
+    pub fn get_nfts_by_contract_opts(
+        &self,
+        chain: Chain,
+        contract: Address,
+        req: GetNftsRequest,
+        opts: RequestOptions,
+    ) -> Result<GetNftsResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_nfts_by_contract_opts(chain, contract, req, opts))
+    }
+
+    /// See [`OpenSeaV2Client::get_nft`].
+    pub fn get_nft(&self, chain: Chain, contract: Address, identifier: String) -> Result<Nft, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_nft(chain, contract, identifier))
+    }
+
+    /// See [`OpenSeaV2Client::get_nft_events`].
+    pub fn get_nft_events(
+        &self,
+        chain: Chain,
+        contract: Address,
+        identifier: String,
+        req: GetEventsRequest,
+    ) -> Result<GetEventsResponse, OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_nft_events(chain, contract, identifier, req))
+    }
+
+    /// See [`OpenSeaV2Client::get_nft_with_last_sale`].
+    pub fn get_nft_with_last_sale(
+        &self,
+        chain: Chain,
+        contract: Address,
+        identifier: String,
+    ) -> Result<(Nft, Option<SaleEvent>), OpenSeaApiError> {
+        self.runtime.block_on(self.inner.get_nft_with_last_sale(chain, contract, identifier))
+    }
+}
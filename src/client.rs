@@ -1,26 +1,182 @@
+use alloy_primitives::{Address, B256};
+use chrono::{DateTime, Utc};
+use futures::{
+    future::{self, Either},
+    stream, Stream, StreamExt, TryStreamExt,
+};
 use reqwest::{
-    header::{self, HeaderMap},
-    Client, ClientBuilder,
+    header::{self, HeaderMap, CONTENT_TYPE, RETRY_AFTER},
+    Client, ClientBuilder, RequestBuilder, Response, ResponseBuilderExt, StatusCode,
 };
+use serde::de::DeserializeOwned;
+use serde_json::Value;
 
+#[cfg(feature = "cache")]
+use crate::constants::MAX_CACHED_RESPONSES;
 use crate::{
-    constants::{API_BASE_MAINNET, API_BASE_TESTNET, PROTOCOL_VERSION},
+    constants::{
+        API_BASE_MAINNET, API_BASE_TESTNET, CANCEL_CONCURRENCY, MAX_CONCURRENT_REQUESTS, MAX_QUERY_LENGTH, MAX_RETRY_DELAY,
+        PROTOCOL_VERSION, RETRY_BASE_DELAY,
+    },
     types::{
         api::{
-            CollectionResponse, FulfillListingRequest, FulfillListingResponse, GetAllListingsRequest, GetAllListingsResponse,
-            OpenSeaDetailedErrorCode::{OrderCannotBeFulfilled, OrderHashDoesNotExist},
-            OpenSeaErrorResponse, RetrieveListingsRequest, RetrieveListingsResponse,
+            events::{AssetEvent, EventType, GetEventsRequest, GetEventsResponse, SaleEvent},
+            nfts::{GetNftsRequest, GetNftsResponse, Nft, NftResponse},
+            orders::{Currency, ItemListing, Order, Price},
+            AccountResponse, CancelOrderRequest, CancelOrderResponse, CollectionResponse, CollectionStatsResponse, ContractResponse,
+            Cursor, FulfillListingRequest, FulfillListingResponse, FulfillOfferRequest, GetAllListingsRequest, GetAllListingsResponse,
+            GetOrderResponse, ListCollectionsRequest, ListCollectionsResponse, OpenSeaDetailedErrorCode, OpenSeaErrorResponse,
+            PartialResult, PostOrderRequest, PostOrderResponse, QueryRequest, RetrieveListingsRequest, RetrieveListingsResponse,
+            RetrieveOffersRequest, RetrieveOffersResponse, TrendingPeriod,
         },
         ApiUrl, Chain, OpenSeaApiError,
     },
 };
+#[cfg(feature = "cache")]
+use reqwest::header::{ETAG, IF_NONE_MATCH};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    future::Future,
+    pin::pin,
+    sync::Arc,
+    time::Duration,
+};
+#[cfg(feature = "cache")]
+use std::{sync::Mutex, time::Instant};
+use tokio::sync::{watch, Semaphore};
+
+/// Check the status of a response and deserialize the body into `T`.
+///
+/// A 429 is returned as [`OpenSeaApiError::RateLimited`] including the `Retry-After` delay if present. An HTML page,
+/// usually a Cloudflare challenge, is returned as [`OpenSeaApiError::Other`] mentioning the status. Other error
+/// statuses are parsed as an OpenSea error body, known messages of a 400 are mapped to an [`OpenSeaDetailedErrorCode`]. If the
+/// body cannot be parsed [`OpenSeaApiError::UnexpectedStatus`] with the raw body is returned.
+pub(crate) async fn handle_response<T: DeserializeOwned>(res: Response) -> Result<T, OpenSeaApiError> {
+    let status = res.status();
+    let is_html = res.headers().get(CONTENT_TYPE).and_then(|v| v.to_str().ok()).is_some_and(|v| v.starts_with("text/html"));
+    if status.is_success() && !is_html {
+        return Ok(res.json::<T>().await?);
+    }
+
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        return Err(OpenSeaApiError::RateLimited { retry_after: retry_after(res.headers()) });
+    }
+
+    if is_html {
+        return Err(OpenSeaApiError::Other(format!(
+            "Received an HTML page with status {status} instead of JSON, the request was likely blocked by Cloudflare"
+        )));
+    }
+
+    let body = res.text().await?;
+    let Ok(err) = serde_json::from_str::<OpenSeaErrorResponse>(&body) else {
+        return Err(OpenSeaApiError::UnexpectedStatus { status, body });
+    };
+    if status == StatusCode::BAD_REQUEST {
+        if let Some(code) = err.errors.first().and_then(|message| OpenSeaDetailedErrorCode::from_message(message)) {
+            return Err(OpenSeaApiError::OpenSeaDetailedError(code));
+        }
+    }
+    Err(OpenSeaApiError::OpenSeaError(err))
+}
+
+/// The `Retry-After` header in seconds.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers.get(RETRY_AFTER).and_then(|v| v.to_str().ok()).and_then(|v| v.parse::<u64>().ok()).map(Duration::from_secs)
+}
+
+/// Whether a request may be sent again after it reached the server, see [`OpenSeaV2Client::send_with_retry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Retry {
+    /// Reads, including POSTs which only compute data like the fulfillment data.
+    Idempotent,
+    /// Requests creating state like posting an order.
+    PreSendOnly,
+}
+
+/// API endpoints for the network of the chain, unless a base url is given.
+fn api_url(base_url: Option<&str>, chain: &Chain) -> ApiUrl {
+    let base_url = match base_url {
+        Some(base_url) => base_url,
+        None if chain.is_test_chain() => API_BASE_TESTNET,
+        None => API_BASE_MAINNET,
+    };
+
+    ApiUrl { base: format!("{base_url}/{PROTOCOL_VERSION}") }
+}
+
+/// Pages of orders starting at `cursor` and following the `next` cursor returned by `fetch` with each page. A page is
+/// only fetched once the previous one was consumed. The stream ends after the last page or the first error.
+fn order_pages<F, Fut>(cursor: Option<Cursor>, fetch: F) -> impl Stream<Item = Result<Vec<Order>, OpenSeaApiError>>
+where
+    F: FnMut(Option<Cursor>) -> Fut,
+    Fut: Future<Output = Result<(Vec<Order>, Option<Cursor>), OpenSeaApiError>>,
+{
+    // `None` once there are no more pages, `Some(None)` for the first page without cursor
+    stream::unfold((Some(cursor), fetch), |(cursor, mut fetch)| async move {
+        let cursor = cursor?;
+        match fetch(cursor).await {
+            Ok((orders, next)) => Some((Ok(orders), (next.map(Some), fetch))),
+            Err(e) => Some((Err(e), (None, fetch))),
+        }
+    })
+}
 
-//. A partial implementation of the OpenSea API v2, supporting the fulfill listing endpoint.
+/// Collect the orders of at most `max_pages` pages, de-duplicated by their order hash. Stops at the first failing
+/// page, keeping the orders of the pages before.
+async fn collect_order_pages(pages: impl Stream<Item = Result<Vec<Order>, OpenSeaApiError>>, max_pages: Option<usize>) -> PartialResult {
+    let mut pages = pin!(pages.take(max_pages.unwrap_or(usize::MAX)));
+    let mut orders = Vec::new();
+    let mut seen = HashSet::new();
+    let mut pages_fetched = 0;
+    while let Some(page) = pages.next().await {
+        let page = match page {
+            Ok(page) => page,
+            Err(error) => return PartialResult { orders, error: Some(error), pages_fetched },
+        };
+        pages_fetched += 1;
+        for order in page {
+            if order.order_hash.as_ref().is_none_or(|hash| seen.insert(hash.clone())) {
+                orders.push(order);
+            }
+        }
+    }
+    PartialResult { orders, error: None, pages_fetched }
+}
+
+/// A partial implementation of the OpenSea API v2.
+///
+/// The client is `Send + Sync` and can be shared across tasks. Cloning is cheap, all clones share the same
+/// connection pool and internal state such as the cache. Any new shared state must be wrapped in an `Arc`
+/// or use atomics to keep these guarantees.
 #[derive(Debug, Clone)]
 pub struct OpenSeaV2Client {
     client: Client,
     chain: Chain,
     url: ApiUrl,
+    max_retries: u32,
+    max_retry_delay: Duration,
+    /// Limits the requests in flight across all calls and clones of the client.
+    limiter: Arc<Semaphore>,
+    /// Set once by [`Self::shutdown`], shared by all clones of the client.
+    shutdown: Arc<watch::Sender<bool>>,
+    max_response_bytes: Option<usize>,
+    #[cfg(feature = "cache")]
+    collection_cache_ttl: Option<Duration>,
+    #[cfg(feature = "cache")]
+    max_cached_responses: usize,
+    /// Last response per url.
+    #[cfg(feature = "cache")]
+    etag_cache: Arc<Mutex<HashMap<String, CachedResponse>>>,
+}
+
+/// A response body cached by [`OpenSeaV2Client::get_cached`].
+#[cfg(feature = "cache")]
+#[derive(Debug, Clone)]
+struct CachedResponse {
+    etag: Option<String>,
+    body: Value,
+    fetched_at: Instant,
 }
 
 /// Configuration for the OpenSea API client.
@@ -28,93 +184,1057 @@ pub struct OpenSeaV2Client {
 pub struct OpenSeaApiConfig {
     pub api_key: Option<String>,
     pub chain: Chain,
+    /// Overrides the API base url derived from the chain, e.g. to use a proxy or a mock server.
+    pub base_url: Option<String>,
+    /// How often a failed request is retried, `0` disables retries. Connection errors are always retried. Rate
+    /// limits and server errors are retried for reads only, posting an order is never retried once it was sent.
+    pub max_retries: u32,
+    /// Maximum delay before a retry, defaults to [`MAX_RETRY_DELAY`](crate::constants::MAX_RETRY_DELAY). A rate limit
+    /// with a longer `Retry-After` is not retried but returned as [`OpenSeaApiError::RateLimited`].
+    pub max_retry_delay: Option<Duration>,
+    /// Only use HTTP/1.1 instead of negotiating HTTP/2, for proxies or networks with connection issues under HTTP/2.
+    pub http1_only: bool,
+    /// Maximum number of requests in flight at once, shared by all calls of the client including the batch helpers.
+    /// Defaults to 4, requests beyond the limit wait for a free slot.
+    pub max_concurrent_requests: Option<usize>,
+    /// Maximum size of a response body, larger responses fail with an error instead of being read into memory.
+    /// Defaults to no limit.
+    pub max_response_bytes: Option<usize>,
+    /// How long collection and contract metadata is served from memory without a request. Defaults to always
+    /// revalidating the cached response with its ETag.
+    #[cfg(feature = "cache")]
+    pub collection_cache_ttl: Option<Duration>,
+    /// Maximum number of cached responses, the least recently fetched response is evicted beyond it. Defaults to
+    /// [`MAX_CACHED_RESPONSES`](crate::constants::MAX_CACHED_RESPONSES).
+    #[cfg(feature = "cache")]
+    pub max_cached_responses: Option<usize>,
+}
+
+/// Options for a single request, overriding the defaults of the client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RequestOptions {
+    /// Timeout of each attempt of the request, replacing the timeout of the `ClientBuilder` the client was built with.
+    pub timeout: Option<Duration>,
+}
+
+impl RequestOptions {
+    fn apply(self, req: RequestBuilder) -> RequestBuilder {
+        match self.timeout {
+            Some(timeout) => req.timeout(timeout),
+            None => req,
+        }
+    }
 }
 
 impl OpenSeaV2Client {
     /// Create a new client with the given configuration.
     pub fn new(cfg: OpenSeaApiConfig) -> Self {
-        let mut builder = ClientBuilder::new();
+        Self::from_builder(cfg, ClientBuilder::new()).unwrap()
+    }
+
+    /// Create a client for mainnet using the given API key, with the defaults of [`OpenSeaApiConfig`] otherwise.
+    /// Panics like [`Self::new`] if the key is not a valid header value.
+    pub fn with_api_key(api_key: impl Into<String>) -> Self {
+        Self::new(OpenSeaApiConfig { api_key: Some(api_key.into()), ..Default::default() })
+    }
+
+    /// Create a new client from a pre-configured `ClientBuilder`, e.g. with a proxy, timeouts or additional default
+    /// headers. The API key header of the configuration is added to the default headers of the builder.
+    pub fn from_builder(cfg: OpenSeaApiConfig, builder: ClientBuilder) -> Result<Self, OpenSeaApiError> {
         let mut headers = HeaderMap::new();
 
         if let Some(ref api_key) = cfg.api_key {
-            headers.insert("X-API-KEY", header::HeaderValue::from_str(api_key).unwrap());
+            let api_key = header::HeaderValue::from_str(api_key).map_err(|e| OpenSeaApiError::Other(format!("Invalid API key: {e}")))?;
+            headers.insert("X-API-KEY", api_key);
         }
 
-        builder = builder.default_headers(headers);
-        let client = builder.build().unwrap();
+        let mut builder = builder.default_headers(headers);
+        if cfg.http1_only {
+            builder = builder.http1_only();
+        }
+        let client = builder.build()?;
 
-        let base_url = if cfg.chain.is_test_chain() { API_BASE_TESTNET } else { API_BASE_MAINNET };
+        Ok(Self {
+            client,
+            url: api_url(cfg.base_url.as_deref(), &cfg.chain),
+            chain: cfg.chain,
+            max_retries: cfg.max_retries,
+            max_retry_delay: cfg.max_retry_delay.unwrap_or(MAX_RETRY_DELAY),
+            limiter: Arc::new(Semaphore::new(cfg.max_concurrent_requests.unwrap_or(MAX_CONCURRENT_REQUESTS).max(1))),
+            shutdown: Arc::new(watch::Sender::new(false)),
+            max_response_bytes: cfg.max_response_bytes,
+            #[cfg(feature = "cache")]
+            collection_cache_ttl: cfg.collection_cache_ttl,
+            #[cfg(feature = "cache")]
+            max_cached_responses: cfg.max_cached_responses.unwrap_or(MAX_CACHED_RESPONSES),
+            #[cfg(feature = "cache")]
+            etag_cache: Default::default(),
+        })
+    }
 
-        let base_url = format!("{base_url}/{PROTOCOL_VERSION}");
+    /// The chain the client was configured with, used by all requests without an explicit chain.
+    pub fn chain(&self) -> &Chain {
+        &self.chain
+    }
 
-        Self { client, chain: cfg.chain, url: ApiUrl { base: base_url } }
+    /// Shut down the client and all its clones. Requests waiting for a retry or a free slot of the concurrency limit
+    /// return [`OpenSeaApiError::Cancelled`] right away, as do all requests sent afterwards. Requests already sent
+    /// are not aborted.
+    pub fn shutdown(&self) {
+        self.shutdown.send_replace(true);
+        self.limiter.close();
+    }
+
+    /// The base url of the client is either mainnet or testnet, so a chain of the other network cannot be queried.
+    fn ensure_same_network(&self, chain: &Chain) -> Result<(), OpenSeaApiError> {
+        if chain.is_test_chain() != self.chain.is_test_chain() {
+            return Err(OpenSeaApiError::Other(format!("Chain {chain} is not on the same network as the client chain {}", self.chain)));
+        }
+        Ok(())
+    }
+
+    /// Send a request, retrying it up to `max_retries` times.
+    ///
+    /// Connection errors are always retried, the request never reached the server. Rate limits (429) and server
+    /// errors (5xx) are only retried for idempotent requests: GETs and the fulfillment data POSTs. Order creation
+    /// POSTs are never retried once sent, as a retry could create a duplicate order. The delay is taken from the
+    /// `Retry-After` header, otherwise it starts at [`RETRY_BASE_DELAY`] and doubles with each attempt up to
+    /// `max_retry_delay`. A `Retry-After` longer than `max_retry_delay` is not waited for, the response is returned.
+    ///
+    /// Each attempt waits for a slot of the concurrency limit, which is held until the body of the returned response
+    /// was read but not while waiting for a retry. After [`Self::shutdown`] no further attempt is made and
+    /// [`OpenSeaApiError::Cancelled`] is returned.
+    async fn send_with_retry(&self, req: RequestBuilder, retry: Retry) -> Result<Response, OpenSeaApiError> {
+        let mut attempt = 0;
+        loop {
+            let permit = self.limiter.acquire().await.map_err(|_| OpenSeaApiError::Cancelled)?;
+            // Requests with a streaming body cannot be cloned and are sent once
+            let Some(next) = req.try_clone() else {
+                return self.read_response(req.send().await?).await;
+            };
+            let res = next.send().await;
+            let retryable = match &res {
+                Err(e) => e.is_connect(),
+                Ok(res) => retry == Retry::Idempotent && (res.status() == StatusCode::TOO_MANY_REQUESTS || res.status().is_server_error()),
+            };
+            if !retryable || attempt >= self.max_retries {
+                return self.read_response(res?).await;
+            }
+            let delay = match res.as_ref().ok().and_then(|res| retry_after(res.headers())) {
+                Some(delay) if delay > self.max_retry_delay => return self.read_response(res?).await,
+                Some(delay) => delay,
+                None => RETRY_BASE_DELAY.saturating_mul(2u32.saturating_pow(attempt)).min(self.max_retry_delay),
+            };
+            drop(permit);
+            if !self.sleep_unless_shutdown(delay).await {
+                return Err(OpenSeaApiError::Cancelled);
+            }
+            attempt += 1;
+        }
+    }
+
+    /// Sleep for `duration`, returning `false` as soon as the client is shut down.
+    async fn sleep_unless_shutdown(&self, duration: Duration) -> bool {
+        let mut shutdown = self.shutdown.subscribe();
+        let sleep = pin!(tokio::time::sleep(duration));
+        let cancelled = pin!(shutdown.wait_for(|shutdown| *shutdown));
+        let res = future::select(sleep, cancelled).await;
+        matches!(res, Either::Left(_))
+    }
+
+    /// Read the body of a response, so the download counts towards the concurrency limit of the caller. Fails once
+    /// the body exceeds `max_response_bytes`.
+    async fn read_response(&self, mut res: Response) -> Result<Response, OpenSeaApiError> {
+        let max = self.max_response_bytes.unwrap_or(usize::MAX);
+        let exceeded = || OpenSeaApiError::Other(format!("Response body exceeds the limit of {max} bytes"));
+        if res.content_length().is_some_and(|len| len > max as u64) {
+            return Err(exceeded());
+        }
+
+        let mut body = Vec::new();
+        while let Some(chunk) = res.chunk().await? {
+            if body.len().saturating_add(chunk.len()) > max {
+                return Err(exceeded());
+            }
+            body.extend_from_slice(&chunk);
+        }
+        // Rebuild the response around the read body, keeping the URL and the extensions like the remote address
+        let mut buffered = http::Response::builder()
+            .status(res.status())
+            .version(res.version())
+            .url(res.url().clone())
+            .body(body)
+            .map_err(|e| OpenSeaApiError::Other(e.to_string()))?;
+        *buffered.headers_mut() = res.headers().clone();
+        buffered.extensions_mut().extend(std::mem::take(res.extensions_mut()));
+        Ok(Response::from(buffered))
+    }
+
+    /// Cache the response of `url`, evicting expired responses and then the least recently fetched ones to stay within
+    /// `max_cached_responses`.
+    #[cfg(feature = "cache")]
+    fn cache_response(&self, url: String, cached: CachedResponse) {
+        let mut cache = self.etag_cache.lock().unwrap();
+        cache.insert(url, cached);
+        if cache.len() <= self.max_cached_responses {
+            return;
+        }
+        // Responses without ETag are only served within the TTL
+        if let Some(ttl) = self.collection_cache_ttl {
+            cache.retain(|_, cached| cached.etag.is_some() || cached.fetched_at.elapsed() < ttl);
+        }
+        while cache.len() > self.max_cached_responses {
+            let Some(oldest) = cache.iter().min_by_key(|(_, cached)| cached.fetched_at).map(|(url, _)| url.clone()) else {
+                break;
+            };
+            cache.remove(&oldest);
+        }
+    }
+
+    /// Send a GET request for a rarely changing resource. With the `cache` feature a response younger than
+    /// `collection_cache_ttl` is returned without a request. Otherwise the ETag of the last response is sent as
+    /// `If-None-Match` and the cached body is returned if the server responds with 304 Not Modified.
+    async fn get_cached<T: DeserializeOwned>(&self, url: String, opts: RequestOptions) -> Result<T, OpenSeaApiError> {
+        #[cfg(feature = "cache")]
+        {
+            let cached = self.etag_cache.lock().unwrap().get(&url).cloned();
+            if let (Some(cached), Some(ttl)) = (&cached, self.collection_cache_ttl) {
+                if cached.fetched_at.elapsed() < ttl {
+                    return Ok(serde_json::from_value(cached.body.clone())?);
+                }
+            }
+
+            let mut req = opts.apply(self.client.get(&url));
+            if let Some(etag) = cached.as_ref().and_then(|cached| cached.etag.as_ref()) {
+                req = req.header(IF_NONE_MATCH, etag);
+            }
+            let res = self.send_with_retry(req, Retry::Idempotent).await?;
+            if let (StatusCode::NOT_MODIFIED, Some(mut cached)) = (res.status(), cached) {
+                cached.fetched_at = Instant::now();
+                self.cache_response(url, cached.clone());
+                return Ok(serde_json::from_value(cached.body)?);
+            }
+
+            let etag = res.headers().get(ETAG).and_then(|v| v.to_str().ok()).map(str::to_owned);
+            let body: Value = handle_response(res).await?;
+            if etag.is_some() || self.collection_cache_ttl.is_some() {
+                self.cache_response(url, CachedResponse { etag, body: body.clone(), fetched_at: Instant::now() });
+            }
+            Ok(serde_json::from_value(body)?)
+        }
+        #[cfg(not(feature = "cache"))]
+        {
+            let res = self.send_with_retry(opts.apply(self.client.get(url)), Retry::Idempotent).await?;
+            handle_response(res).await
+        }
     }
     pub async fn get_collection_by_slug(&self, collection_slug: String) -> Result<CollectionResponse, OpenSeaApiError> {
-        let res = self.client.get(self.url.get_collection(collection_slug)).send().await?.json::<CollectionResponse>().await?;
-        Ok(res)
+        self.get_cached(self.url.get_collection(collection_slug), RequestOptions::default()).await
     }
 
+    /// Retrieve listings filtered by contract, token ids, maker or taker. Returns full `Order` objects.
+    /// To browse all active listings of a collection by its slug use [`Self::get_all_listings`].
     pub async fn retrieve_listings(&self, req: RetrieveListingsRequest) -> Result<RetrieveListingsResponse, OpenSeaApiError> {
-        let res = self
-            .client
-            .get(self.url.get_listings(&self.chain))
-            .query(&req.to_qs_vec()?)
-            .send()
-            .await?
-            .json::<RetrieveListingsResponse>()
-            .await?;
+        self.retrieve_listings_on(self.chain.clone(), req).await
+    }
+
+    /// Same as [`Self::retrieve_listings`] but for another chain than the one configured for the client. The chain
+    /// must be on the same network (mainnet or testnet) as the client.
+    pub async fn retrieve_listings_on(
+        &self,
+        chain: Chain,
+        req: RetrieveListingsRequest,
+    ) -> Result<RetrieveListingsResponse, OpenSeaApiError> {
+        self.retrieve_listings_opts(chain, req, RequestOptions::default()).await
+    }
+
+    /// Same as [`Self::retrieve_listings_on`] with options for this request.
+    ///
+    /// Fails without sending a request if the query string would exceed [`MAX_QUERY_LENGTH`] characters. Request
+    /// fewer `token_ids` at once, split them with [`RetrieveListingsRequest::split_token_ids`] or use
+    /// [`Self::retrieve_all_listings`] which splits them.
+    pub async fn retrieve_listings_opts(
+        &self,
+        chain: Chain,
+        req: RetrieveListingsRequest,
+        opts: RequestOptions,
+    ) -> Result<RetrieveListingsResponse, OpenSeaApiError> {
+        self.ensure_same_network(&chain)?;
+        self.retrieve_listings_page(&chain, req, opts).await.map_err(|e| e.on_chain(&chain))
+    }
+
+    async fn retrieve_listings_page(
+        &self,
+        chain: &Chain,
+        req: RetrieveListingsRequest,
+        opts: RequestOptions,
+    ) -> Result<RetrieveListingsResponse, OpenSeaApiError> {
+        if req.split_token_ids(MAX_QUERY_LENGTH)?.len() > 1 {
+            return Err(OpenSeaApiError::Other(format!(
+                "Query of {} token ids exceeds {MAX_QUERY_LENGTH} characters, split them with split_token_ids or use retrieve_all_listings",
+                req.token_ids.len()
+            )));
+        }
+        let req = opts.apply(self.client.get(self.url.get_listings(chain)).query(&req.query_pairs()?));
+        let res = self.send_with_retry(req, Retry::Idempotent).await?;
+        let mut res: RetrieveListingsResponse = handle_response(res).await?;
+        res.chain = Some(chain.clone());
+        Ok(res)
+    }
+
+    /// Retrieve listings page by page, starting at the `cursor` of `req` and following the `next` cursor until there
+    /// are no more pages or `max_pages` were fetched. Orders are de-duplicated by their order hash. If the query
+    /// string would exceed [`MAX_QUERY_LENGTH`] characters, the `token_ids` are split with
+    /// [`RetrieveListingsRequest::split_token_ids`] and the pages of each chunk are retrieved one after another.
+    pub async fn retrieve_all_listings(
+        &self,
+        req: RetrieveListingsRequest,
+        max_pages: Option<usize>,
+    ) -> Result<Vec<Order>, OpenSeaApiError> {
+        let res = self.retrieve_all_listings_partial(req, max_pages).await;
+        match res.error {
+            Some(error) => Err(error),
+            None => Ok(res.orders),
+        }
+    }
+
+    /// Same as [`Self::retrieve_all_listings`] but stops at the first failing page and returns the orders of the
+    /// pages fetched before together with the error, instead of discarding them.
+    pub async fn retrieve_all_listings_partial(&self, req: RetrieveListingsRequest, max_pages: Option<usize>) -> PartialResult {
+        let requests = match req.split_token_ids(MAX_QUERY_LENGTH) {
+            Ok(requests) => requests,
+            Err(error) => return PartialResult { orders: Vec::new(), error: Some(error), pages_fetched: 0 },
+        };
+        let pages = stream::iter(requests).flat_map(|req| {
+            order_pages(req.cursor.clone(), move |cursor| {
+                let req = RetrieveListingsRequest { cursor, ..req.clone() };
+                async move { self.retrieve_listings(req).await.map(|res| (res.orders, res.next)) }
+            })
+        });
+        collect_order_pages(pages, max_pages).await
+    }
+
+    /// Retrieve the first page of listings of each maker, as the API filters by a single maker only. The requests
+    /// run concurrently with `req` as filter for all makers, its `maker` is replaced. The orders are merged in the
+    /// order of the makers and de-duplicated by their order hash. Fails if any request fails.
+    pub async fn retrieve_listings_for_makers(
+        &self,
+        makers: Vec<Address>,
+        req: RetrieveListingsRequest,
+    ) -> Result<Vec<Order>, OpenSeaApiError> {
+        let responses: Vec<_> = stream::iter(makers)
+            .map(|maker| self.retrieve_listings(RetrieveListingsRequest { maker: Some(maker), ..req.clone() }))
+            .buffered(MAX_CONCURRENT_REQUESTS)
+            .collect()
+            .await;
+
+        let mut orders = Vec::new();
+        let mut seen = HashSet::new();
+        for res in responses {
+            for order in res?.orders {
+                if order.order_hash.as_ref().is_none_or(|hash| seen.insert(hash.clone())) {
+                    orders.push(order);
+                }
+            }
+        }
+        Ok(orders)
+    }
+
+    /// Retrieve offers filtered by contract, token ids, maker or taker.
+    pub async fn retrieve_offers(&self, req: RetrieveOffersRequest) -> Result<RetrieveOffersResponse, OpenSeaApiError> {
+        self.retrieve_offers_on(self.chain.clone(), req).await
+    }
+
+    /// Same as [`Self::retrieve_offers`] but for another chain than the one configured for the client. The chain
+    /// must be on the same network (mainnet or testnet) as the client.
+    pub async fn retrieve_offers_on(&self, chain: Chain, req: RetrieveOffersRequest) -> Result<RetrieveOffersResponse, OpenSeaApiError> {
+        self.retrieve_offers_opts(chain, req, RequestOptions::default()).await
+    }
+
+    /// Same as [`Self::retrieve_offers_on`] with options for this request.
+    pub async fn retrieve_offers_opts(
+        &self,
+        chain: Chain,
+        req: RetrieveOffersRequest,
+        opts: RequestOptions,
+    ) -> Result<RetrieveOffersResponse, OpenSeaApiError> {
+        self.ensure_same_network(&chain)?;
+        self.retrieve_offers_page(&chain, req, opts).await.map_err(|e| e.on_chain(&chain))
+    }
+
+    async fn retrieve_offers_page(
+        &self,
+        chain: &Chain,
+        req: RetrieveOffersRequest,
+        opts: RequestOptions,
+    ) -> Result<RetrieveOffersResponse, OpenSeaApiError> {
+        let req = opts.apply(self.client.get(self.url.get_offers(chain)).query(&req.query_pairs()?));
+        let res = self.send_with_retry(req, Retry::Idempotent).await?;
+        let mut res: RetrieveOffersResponse = handle_response(res).await?;
+        res.chain = Some(chain.clone());
         Ok(res)
     }
 
+    /// Retrieve the offers made by an account, e.g. to audit its outstanding bids. Pass the `next` cursor of the
+    /// previous response in `req` to get the next page.
+    pub async fn get_account_offers(
+        &self,
+        chain: Chain,
+        address: Address,
+        req: GetNftsRequest,
+    ) -> Result<RetrieveOffersResponse, OpenSeaApiError> {
+        let req = RetrieveOffersRequest { maker: Some(address), limit: req.limit, cursor: req.next, ..Default::default() };
+        self.retrieve_offers_on(chain, req).await
+    }
+
+    /// Retrieve offers page by page, starting at the `cursor` of `req` and following the `next` cursor until there are
+    /// no more pages or `max_pages` were fetched. Orders are de-duplicated by their order hash.
+    pub async fn retrieve_all_offers(&self, req: RetrieveOffersRequest, max_pages: Option<usize>) -> Result<Vec<Order>, OpenSeaApiError> {
+        let pages = order_pages(req.cursor.clone(), |cursor| {
+            let req = RetrieveOffersRequest { cursor, ..req.clone() };
+            async move { self.retrieve_offers(req).await.map(|res| (res.orders, res.next)) }
+        });
+        let res = collect_order_pages(pages, max_pages).await;
+        match res.error {
+            Some(error) => Err(error),
+            None => Ok(res.orders),
+        }
+    }
+
+    /// Stream offers page by page, starting at the `cursor` of `req` and following the `next` cursor. A page is
+    /// only requested once the orders of the previous page were consumed. Orders are de-duplicated by their order
+    /// hash. The stream ends after the last page or the first error.
+    pub fn retrieve_offers_stream(&self, req: RetrieveOffersRequest) -> impl Stream<Item = Result<Order, OpenSeaApiError>> {
+        let client = self.clone();
+        let pages = order_pages(req.cursor.clone(), move |cursor| {
+            let (client, req) = (client.clone(), RetrieveOffersRequest { cursor, ..req.clone() });
+            async move { client.retrieve_offers(req).await.map(|res| (res.orders, res.next)) }
+        });
+        let mut seen = HashSet::new();
+        pages
+            .map_ok(|orders| stream::iter(orders.into_iter().map(Ok)))
+            .try_flatten()
+            .try_filter(move |order| future::ready(order.order_hash.as_ref().is_none_or(|hash| seen.insert(hash.clone()))))
+    }
+
     /// Call the fulfill listing endpoint, which returns the arguments necessary
     /// to fulfill an order onchain.
     pub async fn fulfill_listing(&self, req: FulfillListingRequest) -> Result<FulfillListingResponse, OpenSeaApiError> {
-        let res = self.client.post(self.url.fulfill_listing()).json(&req).send().await;
-        match res {
-            Ok(res) => {
-                if res.status() == 400 {
-                    let res = res.json::<OpenSeaErrorResponse>().await?;
-                    let first_error = res.errors.first();
-                    if let Some(first_error) = first_error {
-                        match first_error.as_str() {
-                            "The order_hash you provided does not exist" => {
-                                return Err(OpenSeaApiError::OpenSeaDetailedError(OrderHashDoesNotExist));
-                            }
-                            "This order can not be fulfilled at this time." => {
-                                return Err(OpenSeaApiError::OpenSeaDetailedError(OrderCannotBeFulfilled));
-                            }
-                            &_ => {}
-                        }
-                    }
-                    return Err(OpenSeaApiError::OpenSeaError(res));
-                }
+        self.fulfill_listing_opts(req, RequestOptions::default()).await
+    }
 
-                let res = res.json::<FulfillListingResponse>().await?;
-                Ok(res)
-            }
-            Err(e) => Err(OpenSeaApiError::Reqwest(e)),
+    /// Same as [`Self::fulfill_listing`] with options for this request, e.g. a tight timeout when sniping a listing.
+    pub async fn fulfill_listing_opts(
+        &self,
+        req: FulfillListingRequest,
+        opts: RequestOptions,
+    ) -> Result<FulfillListingResponse, OpenSeaApiError> {
+        let res = self.send_with_retry(opts.apply(self.client.post(self.url.fulfill_listing()).json(&req)), Retry::Idempotent).await?;
+        handle_response(res).await
+    }
+
+    /// Retrieve the fulfillment data to accept an offer, see [`FulfillOfferRequest::from_order`].
+    pub async fn fulfill_offer(&self, req: FulfillOfferRequest) -> Result<FulfillListingResponse, OpenSeaApiError> {
+        self.fulfill_offer_opts(req, RequestOptions::default()).await
+    }
+
+    /// Same as [`Self::fulfill_offer`] with options for this request.
+    pub async fn fulfill_offer_opts(
+        &self,
+        req: FulfillOfferRequest,
+        opts: RequestOptions,
+    ) -> Result<FulfillListingResponse, OpenSeaApiError> {
+        let res = self.send_with_retry(opts.apply(self.client.post(self.url.fulfill_offer()).json(&req)), Retry::Idempotent).await?;
+        handle_response(res).await
+    }
+
+    /// Post a signed listing. The request is not retried once sent, see [`OpenSeaApiConfig::max_retries`]. Check the
+    /// signature of an EOA with [`validate_seaport_signature`](crate::types::api::validate_seaport_signature) first
+    /// to get a descriptive error instead of a 400.
+    pub async fn post_listing(&self, chain: Chain, req: PostOrderRequest) -> Result<PostOrderResponse, OpenSeaApiError> {
+        self.post_listing_opts(chain, req, RequestOptions::default()).await
+    }
+
+    /// Same as [`Self::post_listing`] with options for this request.
+    pub async fn post_listing_opts(
+        &self,
+        chain: Chain,
+        req: PostOrderRequest,
+        opts: RequestOptions,
+    ) -> Result<PostOrderResponse, OpenSeaApiError> {
+        self.ensure_same_network(&chain)?;
+        let res = self.send_with_retry(opts.apply(self.client.post(self.url.get_listings(&chain)).json(&req)), Retry::PreSendOnly).await?;
+        handle_response(res).await
+    }
+
+    /// Post a signed offer. The request is not retried once sent, see [`OpenSeaApiConfig::max_retries`]. Check the
+    /// signature of an EOA with [`validate_seaport_signature`](crate::types::api::validate_seaport_signature) first
+    /// to get a descriptive error instead of a 400.
+    pub async fn post_offer(&self, chain: Chain, req: PostOrderRequest) -> Result<PostOrderResponse, OpenSeaApiError> {
+        self.post_offer_opts(chain, req, RequestOptions::default()).await
+    }
+
+    /// Same as [`Self::post_offer`] with options for this request.
+    pub async fn post_offer_opts(
+        &self,
+        chain: Chain,
+        req: PostOrderRequest,
+        opts: RequestOptions,
+    ) -> Result<PostOrderResponse, OpenSeaApiError> {
+        self.ensure_same_network(&chain)?;
+        let res = self.send_with_retry(opts.apply(self.client.post(self.url.get_offers(&chain)).json(&req)), Retry::PreSendOnly).await?;
+        handle_response(res).await
+    }
+
+    /// Retrieve a single order by its hash, e.g. to check whether it was cancelled or filled in the meantime.
+    pub async fn get_order(&self, chain: Chain, protocol_address: Address, order_hash: B256) -> Result<Order, OpenSeaApiError> {
+        self.ensure_same_network(&chain)?;
+        let url = self.url.get_order(&chain, &protocol_address, &order_hash);
+        let res = self.send_with_retry(self.client.get(url), Retry::Idempotent).await?;
+        let res: GetOrderResponse = handle_response(res).await?;
+        Ok(res.order)
+    }
+
+    /// Cancel an order offchain. The order can still be fulfilled with fulfillment signatures issued before.
+    pub async fn cancel_order(
+        &self,
+        chain: Chain,
+        protocol_address: Address,
+        order_hash: B256,
+        req: CancelOrderRequest,
+    ) -> Result<CancelOrderResponse, OpenSeaApiError> {
+        self.cancel_order_opts(chain, protocol_address, order_hash, req, RequestOptions::default()).await
+    }
+
+    /// Same as [`Self::cancel_order`] with options for this request.
+    pub async fn cancel_order_opts(
+        &self,
+        chain: Chain,
+        protocol_address: Address,
+        order_hash: B256,
+        req: CancelOrderRequest,
+        opts: RequestOptions,
+    ) -> Result<CancelOrderResponse, OpenSeaApiError> {
+        self.ensure_same_network(&chain)?;
+        let url = self.url.cancel_order(&chain, &protocol_address, &order_hash);
+        let res = self.send_with_retry(opts.apply(self.client.post(url).json(&req)), Retry::PreSendOnly).await?;
+        handle_response(res).await
+    }
+
+    /// Cancel many orders of a maker, the signature at each index belongs to the order hash at the same index. At
+    /// most [`CANCEL_CONCURRENCY`] requests run at the same time. The results are returned in the order of the
+    /// hashes, so a single failure does not abort the others. If the number of hashes and signatures differ a single
+    /// error is returned without sending any request.
+    pub async fn cancel_orders(
+        &self,
+        chain: Chain,
+        protocol_address: Address,
+        order_hashes: Vec<B256>,
+        signatures: Vec<String>,
+    ) -> Vec<Result<(), OpenSeaApiError>> {
+        if order_hashes.len() != signatures.len() {
+            return vec![Err(OpenSeaApiError::Other(format!(
+                "Got {} order hashes but {} signatures",
+                order_hashes.len(),
+                signatures.len()
+            )))];
         }
+
+        stream::iter(order_hashes.into_iter().zip(signatures))
+            .map(|(order_hash, signature)| {
+                let req = CancelOrderRequest { offerer_signature: Some(signature) };
+                let chain = chain.clone();
+                async move { self.cancel_order(chain, protocol_address, order_hash, req).await.map(|_| ()) }
+            })
+            .buffered(CANCEL_CONCURRENCY)
+            .collect()
+            .await
     }
 
     pub async fn get_collection(&self, collection_slug: String) -> Result<CollectionResponse, OpenSeaApiError> {
-        let res = self.client.get(self.url.get_collection(collection_slug)).send().await?.json::<CollectionResponse>().await?;
-        Ok(res)
+        self.get_collection_opts(collection_slug, RequestOptions::default()).await
+    }
+
+    /// Same as [`Self::get_collection`] with options for this request.
+    pub async fn get_collection_opts(&self, collection_slug: String, opts: RequestOptions) -> Result<CollectionResponse, OpenSeaApiError> {
+        self.get_cached(self.url.get_collection(collection_slug), opts).await
+    }
+
+    /// Retrieve a page of collections, e.g. all collections on a chain or of a creator.
+    pub async fn get_collections(&self, req: ListCollectionsRequest) -> Result<ListCollectionsResponse, OpenSeaApiError> {
+        let req = self.client.get(self.url.get_collections()).query(&req.query_pairs()?);
+        let res = self.send_with_retry(req, Retry::Idempotent).await?;
+        handle_response(res).await
+    }
+
+    /// Retrieve the first page of trending collections on `chain`, the collections with the highest change over
+    /// `period`. OpenSea has no public trending endpoint, so this orders the collections list by the change of the
+    /// period. Returns an error for [`TrendingPeriod::ThirtyDays`] which the list does not support.
+    pub async fn get_trending_collections(&self, chain: Chain, period: TrendingPeriod) -> Result<ListCollectionsResponse, OpenSeaApiError> {
+        self.ensure_same_network(&chain)?;
+        let Some(order_by) = period.order_by() else {
+            return Err(OpenSeaApiError::Other(format!("Trending period {period:?} is not supported by the collections endpoint")));
+        };
+        self.get_collections(ListCollectionsRequest { chain: Some(chain), order_by: Some(order_by), ..Default::default() }).await
+    }
+
+    /// Retrieve the collections created by `owner`. The API filters collections by the username of their creator
+    /// only, so the username of the account is resolved via [`Self::get_account`] first and set as
+    /// `creator_username` of `req`. Returns an error if the account has no username.
+    pub async fn get_collections_by_owner(
+        &self,
+        owner: Address,
+        mut req: ListCollectionsRequest,
+    ) -> Result<ListCollectionsResponse, OpenSeaApiError> {
+        let account = self.get_account(owner.to_string()).await?;
+        let username = account.username.filter(|username| !username.is_empty());
+        let Some(username) = username else {
+            return Err(OpenSeaApiError::Other(format!("Account {owner} has no username to filter collections by")));
+        };
+        req.creator_username = Some(username);
+        self.get_collections(req).await
+    }
+
+    /// Retrieve stats for a single collection.
+    pub async fn get_collection_stats(&self, collection_slug: String) -> Result<CollectionStatsResponse, OpenSeaApiError> {
+        self.get_collection_stats_opts(collection_slug, RequestOptions::default()).await
+    }
+
+    /// Same as [`Self::get_collection_stats`] with options for this request.
+    pub async fn get_collection_stats_opts(
+        &self,
+        collection_slug: String,
+        opts: RequestOptions,
+    ) -> Result<CollectionStatsResponse, OpenSeaApiError> {
+        let res =
+            self.send_with_retry(opts.apply(self.client.get(self.url.get_collection_stats(collection_slug))), Retry::Idempotent).await?;
+        handle_response(res).await
+    }
+
+    /// Retrieve the floor price of a collection from its stats, `None` if the collection has no floor. A floor in
+    /// ETH has 18 decimals, for other currencies the decimals are taken from the payment token of the collection with
+    /// the `floor_price_symbol`. Returns an error if the collection has no such payment token.
+    pub async fn get_floor_price(&self, collection_slug: String) -> Result<Option<Price>, OpenSeaApiError> {
+        let stats = self.get_collection_stats(collection_slug.clone()).await?;
+        if stats.total.floor_price.is_none() {
+            return Ok(None);
+        }
+        let decimals = match stats.floor_price_currency() {
+            Currency::Eth => 18,
+            Currency::Other(symbol) => {
+                let collection = self.get_collection(collection_slug.clone()).await?;
+                let token = collection.payment_token_for(&symbol).ok_or_else(|| {
+                    OpenSeaApiError::Other(format!(
+                        "Collection {collection_slug} has no payment token {symbol} for the decimals of its floor"
+                    ))
+                })?;
+                u16::try_from(token.decimals).map_err(|e| OpenSeaApiError::Other(format!("Invalid decimals of {symbol}: {e}")))?
+            }
+        };
+        Ok(stats.floor_price(decimals))
     }
 
+    /// Retrieve stats for many collections, running at most `concurrency` requests at the same time.
+    /// The results are returned per slug in completion order, so a single failure does not abort the batch.
+    pub async fn get_collection_stats_batch(
+        &self,
+        slugs: Vec<String>,
+        concurrency: usize,
+    ) -> Vec<(String, Result<CollectionStatsResponse, OpenSeaApiError>)> {
+        stream::iter(slugs)
+            .map(|slug| async move {
+                let res = self.get_collection_stats(slug.clone()).await;
+                (slug, res)
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Retrieve all active listings of a collection by its slug. Use the `next` cursor of the response to page
+    /// through the results. Each `ItemListing` contains the order hash and protocol address needed for
+    /// [`Self::fulfill_listing`]. Unlike [`Self::retrieve_listings`] this endpoint cannot filter by token.
     pub async fn get_all_listings(
         &self,
         collection_slug: String,
         params: GetAllListingsRequest,
     ) -> Result<GetAllListingsResponse, OpenSeaApiError> {
-        let query_parameters = serde_url_params::to_string(&params).unwrap();
-        let res = self
-            .client
-            .get(self.url.get_all_listings(collection_slug, query_parameters))
-            .send()
-            .await?
-            .json::<GetAllListingsResponse>()
-            .await?;
-        Ok(res)
+        self.get_all_listings_opts(collection_slug, params, RequestOptions::default()).await
+    }
+
+    /// Same as [`Self::get_all_listings`] with options for this request.
+    pub async fn get_all_listings_opts(
+        &self,
+        collection_slug: String,
+        params: GetAllListingsRequest,
+        opts: RequestOptions,
+    ) -> Result<GetAllListingsResponse, OpenSeaApiError> {
+        let req = opts.apply(self.client.get(self.url.get_all_listings(collection_slug)).query(&params.query_pairs()?));
+        let res = self.send_with_retry(req, Retry::Idempotent).await?;
+        handle_response(res).await
+    }
+
+    /// Retrieve the cheapest active listing of an NFT of a collection, `None` if the NFT is not listed.
+    pub async fn get_best_listing(&self, collection_slug: String, identifier: String) -> Result<Option<ItemListing>, OpenSeaApiError> {
+        self.get_best_listing_opts(collection_slug, identifier, RequestOptions::default()).await
+    }
+
+    /// Same as [`Self::get_best_listing`] with options for this request.
+    pub async fn get_best_listing_opts(
+        &self,
+        collection_slug: String,
+        identifier: String,
+        opts: RequestOptions,
+    ) -> Result<Option<ItemListing>, OpenSeaApiError> {
+        let req = opts.apply(self.client.get(self.url.get_best_listing(collection_slug, identifier)));
+        let res = self.send_with_retry(req, Retry::Idempotent).await?;
+        if res.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        // An NFT without listing may also be returned as empty object
+        let listing: Value = handle_response(res).await?;
+        if listing.as_object().is_some_and(|listing| listing.is_empty()) {
+            return Ok(None);
+        }
+        Ok(Some(serde_json::from_value(listing)?))
+    }
+
+    /// Retrieve the best listing of many NFTs of a collection, running at most `concurrency` requests at the same
+    /// time. The results are returned per identifier in completion order, so a single failure does not abort the
+    /// others.
+    pub async fn get_best_listings(
+        &self,
+        collection_slug: String,
+        identifiers: Vec<String>,
+        concurrency: usize,
+    ) -> Vec<(String, Result<Option<ItemListing>, OpenSeaApiError>)> {
+        stream::iter(identifiers)
+            .map(|identifier| {
+                let collection_slug = collection_slug.clone();
+                async move {
+                    let res = self.get_best_listing(collection_slug, identifier.clone()).await;
+                    (identifier, res)
+                }
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Retrieve the events of a collection, newest first.
+    pub async fn get_collection_events(
+        &self,
+        collection_slug: String,
+        req: GetEventsRequest,
+    ) -> Result<GetEventsResponse, OpenSeaApiError> {
+        self.get_collection_events_opts(collection_slug, req, RequestOptions::default()).await
+    }
+
+    /// Same as [`Self::get_collection_events`] with options for this request.
+    pub async fn get_collection_events_opts(
+        &self,
+        collection_slug: String,
+        req: GetEventsRequest,
+        opts: RequestOptions,
+    ) -> Result<GetEventsResponse, OpenSeaApiError> {
+        let req = opts.apply(self.client.get(self.url.get_collection_events(collection_slug)).query(&req.query_pairs()?));
+        let res = self.send_with_retry(req, Retry::Idempotent).await?;
+        handle_response(res).await
+    }
+
+    /// Retrieve the latest sales of a collection, newest first. Sale events without NFT, buyer, seller or payment
+    /// are skipped.
+    pub async fn get_sales(
+        &self,
+        collection_slug: String,
+        after: Option<DateTime<Utc>>,
+        limit: Option<u8>,
+    ) -> Result<Vec<SaleEvent>, OpenSeaApiError> {
+        let req = GetEventsRequest { after, event_type: vec![EventType::Sale], limit, ..Default::default() };
+        let res = self.get_collection_events(collection_slug, req).await?;
+        Ok(res.asset_events.iter().filter_map(AssetEvent::to_sale).collect())
+    }
+
+    /// Poll the events of a collection every `poll_interval` and yield only events which were not seen before,
+    /// oldest first. The first poll yields the latest page of events, later polls follow the pages until the events of
+    /// the previous poll are reached. Errors are yielded and polling continues. The stream ends once the client is shut
+    /// down, see [`Self::shutdown`].
+    pub fn stream_collection_events(
+        &self,
+        collection_slug: String,
+        event_type: EventType,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = Result<AssetEvent, OpenSeaApiError>> {
+        struct PollState {
+            client: OpenSeaV2Client,
+            collection_slug: String,
+            event_type: EventType,
+            poll_interval: Duration,
+            polled: bool,
+            after: Option<DateTime<Utc>>,
+            seen: HashMap<String, i64>,
+            pending: VecDeque<AssetEvent>,
+        }
+
+        let state = PollState {
+            client: self.clone(),
+            collection_slug,
+            event_type,
+            poll_interval,
+            polled: false,
+            after: None,
+            seen: HashMap::new(),
+            pending: VecDeque::new(),
+        };
+
+        stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(event) = state.pending.pop_front() {
+                    return Some((Ok(event), state));
+                }
+                if state.polled && !state.client.sleep_unless_shutdown(state.poll_interval).await {
+                    return None;
+                }
+                state.polled = true;
+
+                // Follow the pages until the events at `after` are reached, the first poll only fetches the latest page
+                let mut events = Vec::new();
+                let mut next = None;
+                loop {
+                    let req =
+                        GetEventsRequest { after: state.after, event_type: vec![state.event_type.clone()], next, ..Default::default() };
+                    let res = match state.client.get_collection_events(state.collection_slug.clone(), req).await {
+                        Ok(res) => res,
+                        Err(OpenSeaApiError::Cancelled) => return None,
+                        Err(e) => return Some((Err(e), state)),
+                    };
+                    let reached_after = state.after.is_none_or(|after| res.asset_events.iter().any(|event| event.event_timestamp <= after));
+                    events.extend(res.asset_events);
+                    next = res.next;
+                    if next.is_none() || reached_after {
+                        break;
+                    }
+                }
+
+                // Events are returned newest first
+                for event in events.into_iter().rev() {
+                    let timestamp = event.event_timestamp.timestamp();
+                    if state.seen.insert(event.dedup_key(), timestamp).is_none() {
+                        if state.after.is_none_or(|after| event.event_timestamp > after) {
+                            state.after = Some(event.event_timestamp);
+                        }
+                        state.pending.push_back(event);
+                    }
+                }
+                // Only events at or after the latest timestamp can be returned again
+                if let Some(after) = state.after {
+                    state.seen.retain(|_, timestamp| *timestamp >= after.timestamp());
+                }
+            }
+        })
+    }
+
+    /// Poll the sale events of a collection until a sale of the order with `order_hash` is observed. Returns
+    /// `Ok(None)` if no matching sale was found within `timeout`, and the first error encountered while polling.
+    pub async fn wait_for_fill(
+        &self,
+        collection_slug: String,
+        order_hash: String,
+        timeout: Duration,
+        poll_interval: Duration,
+    ) -> Result<Option<AssetEvent>, OpenSeaApiError> {
+        let mut events = std::pin::pin!(self.stream_collection_events(collection_slug, EventType::Sale, poll_interval));
+        let wait = async {
+            while let Some(event) = events.next().await {
+                let event = event?;
+                if event.order_hash.as_ref().is_some_and(|hash| hash.eq_ignore_ascii_case(&order_hash)) {
+                    return Ok(Some(event));
+                }
+            }
+            Ok(None)
+        };
+        tokio::time::timeout(timeout, wait).await.unwrap_or(Ok(None))
+    }
+
+    /// Retrieve a contract, including the slug of its collection.
+    pub async fn get_contract(&self, chain: Chain, address: Address) -> Result<ContractResponse, OpenSeaApiError> {
+        self.get_contract_opts(chain, address, RequestOptions::default()).await
+    }
+
+    /// Same as [`Self::get_contract`] with options for this request.
+    pub async fn get_contract_opts(
+        &self,
+        chain: Chain,
+        address: Address,
+        opts: RequestOptions,
+    ) -> Result<ContractResponse, OpenSeaApiError> {
+        self.ensure_same_network(&chain)?;
+        self.get_cached(self.url.get_contract(&chain, &address), opts).await
+    }
+
+    /// Retrieve the collection of a contract, resolving its slug via [`Self::get_contract`] first. Returns an error if
+    /// the contract does not belong to a collection.
+    pub async fn collection_for_contract(&self, chain: Chain, address: Address) -> Result<CollectionResponse, OpenSeaApiError> {
+        let contract = self.get_contract(chain.clone(), address).await?;
+        let Some(collection_slug) = contract.collection else {
+            return Err(OpenSeaApiError::Other(format!("Contract {address} on {chain} has no collection")));
+        };
+        self.get_collection(collection_slug).await
+    }
+
+    /// Retrieve an account by its address or OpenSea username.
+    pub async fn get_account(&self, address_or_username: String) -> Result<AccountResponse, OpenSeaApiError> {
+        self.get_account_opts(address_or_username, RequestOptions::default()).await
+    }
+
+    /// Same as [`Self::get_account`] with options for this request.
+    pub async fn get_account_opts(&self, address_or_username: String, opts: RequestOptions) -> Result<AccountResponse, OpenSeaApiError> {
+        let res = self.send_with_retry(opts.apply(self.client.get(self.url.get_account(&address_or_username))), Retry::Idempotent).await?;
+        handle_response(res).await
+    }
+
+    /// Resolve an OpenSea username to the address of its account with [`Self::get_account`]. Returns an error naming
+    /// the username if no account with the username exists.
+    pub async fn resolve_address(&self, username: String) -> Result<Address, OpenSeaApiError> {
+        let err = match self.get_account(username.clone()).await {
+            Ok(account) => return Ok(account.address),
+            Err(err) => err,
+        };
+        // The 404 of an unknown username has an OpenSea error body saying the account was not found
+        let not_found = match &err {
+            OpenSeaApiError::UnexpectedStatus { status, .. } => *status == StatusCode::NOT_FOUND,
+            OpenSeaApiError::OpenSeaError(res) => res.errors.iter().any(|message| message.to_lowercase().contains("not found")),
+            _ => false,
+        };
+        if not_found {
+            return Err(OpenSeaApiError::Other(format!("No account found for username {username}")));
+        }
+        Err(err)
+    }
+
+    /// Retrieve all NFTs of a contract. Use the `next` cursor of the response to page through the results.
+    pub async fn get_nfts_by_contract(
+        &self,
+        chain: Chain,
+        contract: Address,
+        req: GetNftsRequest,
+    ) -> Result<GetNftsResponse, OpenSeaApiError> {
+        self.get_nfts_by_contract_opts(chain, contract, req, RequestOptions::default()).await
+    }
+
+    /// Same as [`Self::get_nfts_by_contract`] with options for this request.
+    pub async fn get_nfts_by_contract_opts(
+        &self,
+        chain: Chain,
+        contract: Address,
+        req: GetNftsRequest,
+        opts: RequestOptions,
+    ) -> Result<GetNftsResponse, OpenSeaApiError> {
+        self.ensure_same_network(&chain)?;
+        let req = opts.apply(self.client.get(self.url.get_nfts_by_contract(&chain, &contract)).query(&req.query_pairs()?));
+        let res = self.send_with_retry(req, Retry::Idempotent).await?;
+        handle_response(res).await
+    }
+
+    /// Retrieve a single NFT.
+    pub async fn get_nft(&self, chain: Chain, contract: Address, identifier: String) -> Result<Nft, OpenSeaApiError> {
+        self.ensure_same_network(&chain)?;
+        let res = self.send_with_retry(self.client.get(self.url.get_nft(&chain, &contract, &identifier)), Retry::Idempotent).await?;
+        let res: NftResponse = handle_response(res).await?;
+        Ok(res.nft)
+    }
+
+    /// Retrieve the events of a single NFT, newest first.
+    pub async fn get_nft_events(
+        &self,
+        chain: Chain,
+        contract: Address,
+        identifier: String,
+        req: GetEventsRequest,
+    ) -> Result<GetEventsResponse, OpenSeaApiError> {
+        self.ensure_same_network(&chain)?;
+        let req = self.client.get(self.url.get_nft_events(&chain, &contract, &identifier)).query(&req.query_pairs()?);
+        let res = self.send_with_retry(req, Retry::Idempotent).await?;
+        handle_response(res).await
+    }
+
+    /// Retrieve a single NFT together with its most recent sale, `None` if it was never sold.
+    pub async fn get_nft_with_last_sale(
+        &self,
+        chain: Chain,
+        contract: Address,
+        identifier: String,
+    ) -> Result<(Nft, Option<SaleEvent>), OpenSeaApiError> {
+        let nft = self.get_nft(chain.clone(), contract, identifier.clone()).await?;
+        let req = GetEventsRequest { event_type: vec![EventType::Sale], limit: Some(1), ..Default::default() };
+        let events = self.get_nft_events(chain, contract, identifier, req).await?;
+        let last_sale = events.asset_events.iter().find_map(AssetEvent::to_sale);
+        Ok((nft, last_sale))
+    }
+}
+
+/// Client for mainnet and testnet chains at the same time. Each request is routed to the API of the network of the
+/// given chain, so e.g. Ethereum and Sepolia can be queried interchangeably. Both networks share one connection pool.
+#[derive(Debug, Clone)]
+pub struct OpenSeaV2MultiClient {
+    mainnet: OpenSeaV2Client,
+    testnet: OpenSeaV2Client,
+}
+
+impl OpenSeaV2MultiClient {
+    /// Create a new client with the given configuration. The chain of the configuration is ignored.
+    pub fn new(cfg: OpenSeaApiConfig) -> Self {
+        Self::from_builder(cfg, ClientBuilder::new()).unwrap()
+    }
+
+    /// Create a new client from a pre-configured `ClientBuilder`, see [`OpenSeaV2Client::from_builder`].
+    pub fn from_builder(cfg: OpenSeaApiConfig, builder: ClientBuilder) -> Result<Self, OpenSeaApiError> {
+        let base_url = cfg.base_url.clone();
+        let mainnet = OpenSeaV2Client::from_builder(OpenSeaApiConfig { chain: Chain::Ethereum, ..cfg }, builder)?;
+        let mut testnet = mainnet.clone();
+        testnet.chain = Chain::Sepolia;
+        testnet.url = api_url(base_url.as_deref(), &testnet.chain);
+        Ok(Self { mainnet, testnet })
+    }
+
+    /// The client for the network of the chain.
+    pub fn client_for(&self, chain: &Chain) -> &OpenSeaV2Client {
+        if chain.is_test_chain() {
+            &self.testnet
+        } else {
+            &self.mainnet
+        }
+    }
+
+    /// Shut down the clients of both networks, see [`OpenSeaV2Client::shutdown`].
+    pub fn shutdown(&self) {
+        // The testnet client is a clone of the mainnet client sharing its shutdown signal
+        self.mainnet.shutdown();
+    }
+
+    /// See [`OpenSeaV2Client::retrieve_listings`].
+    pub async fn retrieve_listings(&self, chain: Chain, req: RetrieveListingsRequest) -> Result<RetrieveListingsResponse, OpenSeaApiError> {
+        self.client_for(&chain).retrieve_listings_on(chain, req).await
+    }
+
+    /// See [`OpenSeaV2Client::retrieve_offers`].
+    pub async fn retrieve_offers(&self, chain: Chain, req: RetrieveOffersRequest) -> Result<RetrieveOffersResponse, OpenSeaApiError> {
+        self.client_for(&chain).retrieve_offers_on(chain, req).await
+    }
+
+    /// See [`OpenSeaV2Client::fulfill_listing`], routed by the chain of the listing.
+    pub async fn fulfill_listing(&self, req: FulfillListingRequest) -> Result<FulfillListingResponse, OpenSeaApiError> {
+        self.client_for(&req.listing.chain).fulfill_listing(req).await
+    }
+
+    /// See [`OpenSeaV2Client::get_nfts_by_contract`].
+    pub async fn get_nfts_by_contract(
+        &self,
+        chain: Chain,
+        contract: Address,
+        req: GetNftsRequest,
+    ) -> Result<GetNftsResponse, OpenSeaApiError> {
+        self.client_for(&chain).get_nfts_by_contract(chain, contract, req).await
     }
 }
 
@@ -122,12 +1242,143 @@ impl OpenSeaV2Client {
 mod tests {
 
     use super::*;
-    use crate::types::api::orders::{Counter, Currency};
-    use alloy_primitives::U256;
+    use crate::types::api::{
+        orders::{Counter, Currency},
+        FulfillmentData, InputData, Transaction,
+    };
+    use alloy_primitives::{B256, U256};
     use chrono::DateTime;
     use std::path::PathBuf;
     use std::str::FromStr;
 
+    fn response(status: u16, body: &'static str) -> Response {
+        Response::from(http::Response::builder().status(status).body(body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn handle_response_deserializes_success() {
+        let res: OpenSeaErrorResponse = handle_response(response(200, r#"{ "errors": [] }"#)).await.unwrap();
+        assert!(res.errors.is_empty());
+    }
+
+    #[tokio::test]
+    async fn handle_response_detects_rate_limit() {
+        let res = Response::from(http::Response::builder().status(429).header("Retry-After", "5").body("").unwrap());
+        let err = handle_response::<OpenSeaErrorResponse>(res).await.unwrap_err();
+        assert!(matches!(err, OpenSeaApiError::RateLimited { retry_after: Some(d) } if d == Duration::from_secs(5)));
+
+        let err = handle_response::<OpenSeaErrorResponse>(response(429, "")).await.unwrap_err();
+        assert!(matches!(err, OpenSeaApiError::RateLimited { retry_after: None }));
+    }
+
+    #[tokio::test]
+    async fn handle_response_maps_known_error_messages() {
+        let res = response(400, r#"{ "errors": ["The order_hash you provided does not exist"] }"#);
+        let err = handle_response::<OpenSeaErrorResponse>(res).await.unwrap_err();
+        assert!(matches!(err, OpenSeaApiError::OpenSeaDetailedError(OpenSeaDetailedErrorCode::OrderHashDoesNotExist)));
+
+        let res = response(400, r#"{ "errors": ["Invalid signature"] }"#);
+        let err = handle_response::<OpenSeaErrorResponse>(res).await.unwrap_err();
+        assert!(matches!(err, OpenSeaApiError::OpenSeaDetailedError(OpenSeaDetailedErrorCode::InvalidSignature)));
+
+        let res = response(400, r#"{ "errors": ["Something else went wrong"] }"#);
+        let err = handle_response::<OpenSeaErrorResponse>(res).await.unwrap_err();
+        assert!(matches!(err, OpenSeaApiError::OpenSeaError(_)));
+    }
+
+    #[tokio::test]
+    async fn handle_response_parses_error_body() {
+        let err = handle_response::<OpenSeaErrorResponse>(response(404, r#"{ "errors": ["Not found"] }"#)).await.unwrap_err();
+        assert!(matches!(err, OpenSeaApiError::OpenSeaError(e) if e.errors == vec!["Not found".to_string()]));
+
+        let err = handle_response::<OpenSeaErrorResponse>(response(500, "Internal Server Error")).await.unwrap_err();
+        assert!(matches!(err, OpenSeaApiError::UnexpectedStatus { status, body } if status == 500 && body == "Internal Server Error"));
+    }
+
+    #[tokio::test]
+    async fn handle_response_detects_html_pages() {
+        for status in [403, 503, 200] {
+            let res = Response::from(
+                http::Response::builder()
+                    .status(status)
+                    .header("Content-Type", "text/html; charset=UTF-8")
+                    .body("<!DOCTYPE html><html><head><title>Just a moment...</title></head></html>")
+                    .unwrap(),
+            );
+            let err = handle_response::<OpenSeaErrorResponse>(res).await.unwrap_err();
+            assert!(matches!(err, OpenSeaApiError::Other(msg) if msg.contains("Cloudflare") && msg.contains(&status.to_string())));
+        }
+    }
+
+    #[test]
+    fn multi_client_routes_by_chain() {
+        let client = OpenSeaV2MultiClient::new(OpenSeaApiConfig::default());
+        assert_eq!(client.client_for(&Chain::Ethereum).url.base, "https://api.opensea.io/api/v2");
+        assert_eq!(client.client_for(&Chain::Polygon).url.base, "https://api.opensea.io/api/v2");
+        assert_eq!(client.client_for(&Chain::Sepolia).url.base, "https://testnets-api.opensea.io/api/v2");
+        assert_eq!(client.client_for(&Chain::Sepolia).chain, Chain::Sepolia);
+        assert!(client.client_for(&Chain::Sepolia).ensure_same_network(&Chain::BaseGoerli).is_ok());
+        assert!(client.client_for(&Chain::Ethereum).ensure_same_network(&Chain::Base).is_ok());
+    }
+
+    #[tokio::test]
+    async fn with_api_key_sends_key_to_mainnet() {
+        use wiremock::{
+            matchers::{header, method, path},
+            Mock, MockServer, ResponseTemplate,
+        };
+
+        let mut client = OpenSeaV2Client::with_api_key("test-key");
+        assert_eq!(client.chain, Chain::Ethereum);
+        assert_eq!(client.url.base, "https://api.opensea.io/api/v2");
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v2/accounts/test"))
+            .and(header("X-API-KEY", "test-key"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "address": "0x67d58520775af7848f3ee2adaa227435f5a91a04",
+                "username": "test"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        client.url = api_url(Some(&server.uri()), &client.chain);
+        client.get_account("test".to_string()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn read_response_keeps_url_and_remote_address() {
+        use wiremock::{matchers::method, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET")).respond_with(ResponseTemplate::new(200).set_body_string("{}")).mount(&server).await;
+        let client = OpenSeaV2Client::new(OpenSeaApiConfig { max_response_bytes: Some(1024), ..Default::default() });
+        let url = format!("{}/v2/accounts/test", server.uri());
+        let res = client.send_with_retry(client.client.get(&url), Retry::Idempotent).await.unwrap();
+        assert_eq!(res.url().as_str(), url);
+        assert_eq!(res.remote_addr(), Some(*server.address()));
+        assert_eq!(res.text().await.unwrap(), "{}");
+    }
+
+    #[test]
+    fn client_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<OpenSeaV2Client>();
+        assert_send_sync::<OpenSeaV2MultiClient>();
+        assert_send_sync::<OpenSeaApiError>();
+    }
+
+    #[test]
+    fn can_build_base_url_for_mainnet_and_testnet() {
+        let client = OpenSeaV2Client::new(OpenSeaApiConfig { chain: Chain::Ethereum, ..Default::default() });
+        assert_eq!(client.url.base, "https://api.opensea.io/api/v2");
+
+        let client = OpenSeaV2Client::new(OpenSeaApiConfig { chain: Chain::Sepolia, ..Default::default() });
+        assert_eq!(client.url.base, "https://testnets-api.opensea.io/api/v2");
+        assert_eq!(client.url.get_listings(&Chain::Sepolia), "https://testnets-api.opensea.io/api/v2/orders/sepolia/seaport/listings");
+    }
+
     #[test]
     fn can_deserialize_get_all_listings_response() {
         let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
@@ -146,6 +1397,41 @@ mod tests {
         assert_eq!(res.listings.first().unwrap().price.current.currency, Currency::Other("USD".to_string()));
     }
 
+    #[test]
+    fn get_all_listings_response_contains_fulfillment_data() {
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("resources/response_get_all_listings.json");
+        let res = std::fs::read_to_string(d).unwrap();
+        let res: GetAllListingsResponse = serde_json::from_str(&res).unwrap();
+        let listing = res.listings.first().unwrap();
+        assert!(B256::from_str(&listing.order_hash).is_ok());
+        assert_eq!(listing.chain, Chain::Ethereum);
+        assert_eq!(listing.protocol_address, Some("0x00000000000000adc04c56bf30ac9d3c0aaf14dc".to_string()));
+        assert!(res.next.is_some());
+    }
+
+    #[test]
+    fn can_convert_item_listing_to_fulfill_request() {
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("resources/response_get_all_listings.json");
+        let res = std::fs::read_to_string(d).unwrap();
+        let res: GetAllListingsResponse = serde_json::from_str(&res).unwrap();
+        let fulfiller = Address::from_str("0x67d58520775af7848f3ee2adaa227435f5a91a04").unwrap();
+
+        let mut listing = res.listings.first().unwrap().clone();
+        let req = listing.to_fulfill_request(fulfiller).unwrap();
+        assert_eq!(req.listing.hash, B256::from_str(&listing.order_hash).unwrap());
+        assert_eq!(req.listing.chain, Chain::Ethereum);
+        assert_eq!(req.fulfiller.address, fulfiller);
+        let json = serde_json::to_value(&req).unwrap();
+        assert_eq!(json["listing"]["protocol_address"], "0x00000000000000ADc04C56Bf30aC9d3c0aAF14dC");
+
+        listing.protocol_address = Some("0x0000000000000000000000000000000000000001".to_string());
+        assert!(listing.to_fulfill_request(fulfiller).is_err());
+        listing.protocol_address = None;
+        assert!(listing.to_fulfill_request(fulfiller).is_err());
+    }
+
     #[test]
     fn can_deserialize_fulfill_listing_v6_response() {
         let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
@@ -154,7 +1440,69 @@ mod tests {
         let res = std::fs::read_to_string(d).unwrap();
         let res: FulfillListingResponse = serde_json::from_str(&res).unwrap();
         assert_eq!(res.protocol, "seaport1.6");
-        assert_eq!(res.fulfillment_data.transaction.value, U256::from_str("23690000000000000000").unwrap());
+        assert_eq!(res.fulfillment_data.transaction().unwrap().value, U256::from_str("23690000000000000000").unwrap());
+        assert!(matches!(res.fulfillment_data.transaction().unwrap().input_data, InputData::Basic { .. }));
+        assert!(res.is_onchain());
+    }
+
+    #[test]
+    fn can_serialize_transaction_value_above_u128() {
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("resources/response_fulfill_listing_1.6.json");
+        let res = std::fs::read_to_string(d).unwrap();
+        let mut res: FulfillListingResponse = serde_json::from_str(&res).unwrap();
+        let value = U256::from(u128::MAX) + U256::from(1);
+        let FulfillmentData::Transaction { transaction } = &mut res.fulfillment_data else {
+            panic!("Expected onchain fulfillment data");
+        };
+        transaction.value = value;
+
+        let json = serde_json::to_value(&**transaction).unwrap();
+        assert_eq!(json["value"], "340282366920938463463374607431768211456");
+        let transaction: Transaction = serde_json::from_value(json).unwrap();
+        assert_eq!(transaction.value, value);
+    }
+
+    #[test]
+    fn can_deserialize_fulfill_listing_advanced_response() {
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("resources/response_fulfill_listing_advanced.json");
+        let res = std::fs::read_to_string(d).unwrap();
+        let res: FulfillListingResponse = serde_json::from_str(&res).unwrap();
+        let transaction = res.fulfillment_data.transaction().unwrap();
+        assert!(transaction.function.starts_with("fulfillAdvancedOrder"));
+        let InputData::Advanced(ref params) = transaction.input_data else {
+            panic!("Expected advanced order input data");
+        };
+        assert_eq!(params.advanced_order.numerator, 1);
+        assert_eq!(params.advanced_order.parameters.consideration.len(), 2);
+        assert_eq!(params.criteria_resolvers.first().unwrap().identifier, U256::from(8606));
+    }
+
+    #[test]
+    fn can_deserialize_fulfill_listing_signature_response() {
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("resources/response_fulfill_listing_signature.json");
+        let res = std::fs::read_to_string(d).unwrap();
+        let res: FulfillListingResponse = serde_json::from_str(&res).unwrap();
+        assert!(!res.is_onchain());
+        assert!(res.fulfillment_data.transaction().is_none());
+        let FulfillmentData::Signature { orders } = &res.fulfillment_data else {
+            panic!("Expected signature fulfillment data");
+        };
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].parameters.consideration.len(), 2);
+        assert!(orders[0].signature.as_bytes().is_some());
+
+        let json = serde_json::to_value(&res.fulfillment_data).unwrap();
+        assert!(json.get("transaction").is_none());
+        assert_eq!(json["orders"].as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn fulfill_listing_response_requires_transaction_or_orders() {
+        let res = serde_json::from_value::<FulfillListingResponse>(serde_json::json!({"protocol": "seaport1.6", "fulfillment_data": {}}));
+        assert!(res.is_err());
     }
 
     #[test]
@@ -165,7 +1513,7 @@ mod tests {
         let res = std::fs::read_to_string(d).unwrap();
         let res: FulfillListingResponse = serde_json::from_str(&res).unwrap();
         assert_eq!(res.protocol, "seaport1.5");
-        assert_eq!(res.fulfillment_data.transaction.value, U256::from_str("20000000000000000").unwrap());
+        assert_eq!(res.fulfillment_data.transaction().unwrap().value, U256::from_str("20000000000000000").unwrap());
     }
 
     #[test]
@@ -176,6 +1524,6 @@ mod tests {
         let res = std::fs::read_to_string(d).unwrap();
         let res: FulfillListingResponse = serde_json::from_str(&res).unwrap();
         assert_eq!(res.protocol, "seaport1.4");
-        assert_eq!(res.fulfillment_data.transaction.value, U256::from_str("1780000000000000000").unwrap());
+        assert_eq!(res.fulfillment_data.transaction().unwrap().value, U256::from_str("1780000000000000000").unwrap());
     }
 }
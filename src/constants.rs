@@ -1,3 +1,6 @@
+use alloy_primitives::{address, b256, Address, B256};
+use std::time::Duration;
+
 /// Address for the Seaport V1 contract.
 pub const SEAPORT_V1: &str = "0x00000000006c3852cbEf3e08E8dF289169EdE581";
 
@@ -9,7 +12,58 @@ pub const SEAPORT_V5: &str = "0x00000000000000ADc04C56Bf30aC9d3c0aAF14dC";
 /// Address for the Seaport V6 contract.
 pub const SEAPORT_V6: &str = "0x0000000000000068f116a894984e2db1123eb395";
 
+/// Conduit key of the OpenSea conduit, which is the same on all EVM chains. Approvals for Seaport orders listed on
+/// OpenSea are given to this conduit.
+pub const OPENSEA_CONDUIT_KEY: B256 = b256!("0000007b02230091a7ed01230072f7006a004d60a8d4e71d599b8104250f0000");
+
+/// Recipient of the OpenSea marketplace fee, all other collection fees go to the creators.
+pub const OPENSEA_FEE_RECIPIENT: Address = address!("0000a26b00c1f0df003000390027140000faa719");
+
+/// Wrapped ether on Ethereum.
+pub const WETH_ETHEREUM: Address = address!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2");
+/// Wrapped MATIC on Polygon.
+pub const WMATIC_POLYGON: Address = address!("0d500B1d8E8eF31E21C99d1Db9A6444d3ADf1270");
+/// Wrapped ether on the OP stack chains Base, Optimism and Zora, deployed at the same predeploy address.
+pub const WETH_OP_STACK: Address = address!("4200000000000000000000000000000000000006");
+/// Wrapped ether on Arbitrum One.
+pub const WETH_ARBITRUM: Address = address!("82aF49447D8a07e3bd95BD0d56f35241523fBab1");
+/// Wrapped ether on Arbitrum Nova.
+pub const WETH_ARBITRUM_NOVA: Address = address!("722E8BdD2ce80A4422E880164f2079488e115365");
+/// Wrapped AVAX on Avalanche.
+pub const WAVAX_AVALANCHE: Address = address!("B31f66AA3C1e785363F0875A1B74E27b85FD66c7");
+/// Wrapped BNB on BSC.
+pub const WBNB_BSC: Address = address!("bb4CdB9CBd36B01bD1cBaEBF2De08d9173bc095c");
+/// Wrapped ether on Goerli.
+pub const WETH_GOERLI: Address = address!("B4FBF271143F4FBf7B91A5ded31805e42b2208d6");
+/// Wrapped ether on Sepolia.
+pub const WETH_SEPOLIA: Address = address!("fFf9976782d46CC05630D1f6eBAb18b2324d6B14");
+/// Wrapped MATIC on Mumbai.
+pub const WMATIC_MUMBAI: Address = address!("9c3C9283D3e44854697Cd22D3Faa240Cfb032889");
+/// Wrapped AVAX on Avalanche Fuji.
+pub const WAVAX_FUJI: Address = address!("d00ae08403B9bbb9124bB305C09058E32C39A48c");
+
+/// Version of the OpenSea API, appended to the base url.
 pub const PROTOCOL_VERSION: &str = "v2";
 
+/// Base url of the API for mainnet chains.
 pub const API_BASE_MAINNET: &str = "https://api.opensea.io/api";
-pub const API_BASE_TESTNET: &str = "https://testnets-api.opensea.io";
+/// Base url of the API for testnet chains.
+pub const API_BASE_TESTNET: &str = "https://testnets-api.opensea.io/api";
+
+/// Maximum length of the query string of a listings request before its `token_ids` are split into several requests.
+pub const MAX_QUERY_LENGTH: usize = 2000;
+
+/// Delay before the first retry of a request without `Retry-After` header. Doubles with each attempt.
+pub const RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// Default maximum delay before a retry. A longer `Retry-After` is returned as rate limit error instead of waiting.
+pub const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// Maximum number of concurrent requests when cancelling many orders.
+pub const CANCEL_CONCURRENCY: usize = 4;
+
+/// Default maximum number of requests in flight per client.
+pub const MAX_CONCURRENT_REQUESTS: usize = 4;
+
+/// Default maximum number of responses cached per client with the `cache` feature.
+pub const MAX_CACHED_RESPONSES: usize = 1000;
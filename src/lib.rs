@@ -6,16 +6,27 @@
 /// This module contains the core client implementation.
 pub mod client;
 
+/// This module contains the blocking client.
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
 /// This module contains constants used by the client.
-mod constants;
+pub mod constants;
+
+/// This module contains helpers for Seaport amount calculations.
+pub mod math;
 
 /// This module contains the core type definitions for the client.
 pub mod types;
 
-pub use client::{OpenSeaApiConfig, OpenSeaV2Client};
+pub use client::{OpenSeaApiConfig, OpenSeaV2Client, OpenSeaV2MultiClient, RequestOptions};
+
+#[cfg(feature = "blocking")]
+pub use blocking::OpenSeaV2ClientBlocking;
 
 //XXX Suppress false positive unused_crate_dependencies warning
 #[cfg(test)]
 mod test {
     use tokio as _;
+    use wiremock as _;
 }
@@ -0,0 +1,88 @@
+use alloy_primitives::U256;
+
+/// Linearly interpolate an amount between `start` at `start_time` and `end` at `end_time`, as Seaport does for the
+/// items of Dutch auctions. Before the start the start amount applies, after the end the end amount. Seaport rounds
+/// consideration amounts up and offer amounts down, selected by `round_up`. Intermediate results saturate instead of
+/// overflowing.
+pub fn interpolate_amount(start: U256, end: U256, start_time: i64, end_time: i64, now: i64, round_up: bool) -> U256 {
+    if now >= end_time {
+        return end;
+    }
+    if now <= start_time {
+        return start;
+    }
+    let duration = U256::from(end_time.abs_diff(start_time));
+    let elapsed = U256::from(now.abs_diff(start_time));
+    let remaining = U256::from(end_time.abs_diff(now));
+    let total = start.saturating_mul(remaining).saturating_add(end.saturating_mul(elapsed));
+    if round_up && !total.is_zero() {
+        (total - U256::from(1)) / duration + U256::from(1)
+    } else {
+        total / duration
+    }
+}
+
+/// Parse a decimal amount like `1.25` into base units with `decimals` decimals, e.g. ETH into wei. Digits beyond
+/// `decimals` are truncated. `None` if the value is not a non-negative decimal number or overflows.
+pub fn parse_units(value: &str, decimals: u8) -> Option<U256> {
+    let (int, frac) = value.split_once('.').unwrap_or((value, ""));
+    if int.is_empty() && frac.is_empty() || !int.chars().chain(frac.chars()).all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let frac: String = frac.chars().chain(std::iter::repeat('0')).take(decimals as usize).collect();
+    let int = if int.is_empty() { U256::ZERO } else { U256::from_str_radix(int, 10).ok()? };
+    let frac = if frac.is_empty() { U256::ZERO } else { U256::from_str_radix(&frac, 10).ok()? };
+    int.checked_mul(U256::from(10).checked_pow(U256::from(decimals))?)?.checked_add(frac)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_interpolate_amount() {
+        let (start, end) = (U256::from(2000), U256::from(1000));
+        assert_eq!(interpolate_amount(start, end, 100, 200, 100, false), start);
+        assert_eq!(interpolate_amount(start, end, 100, 200, 200, false), end);
+        assert_eq!(interpolate_amount(start, end, 100, 200, 150, false), U256::from(1500));
+        assert_eq!(interpolate_amount(start, end, 100, 200, 125, true), U256::from(1750));
+
+        // increasing amounts, e.g. English auctions
+        assert_eq!(interpolate_amount(end, start, 100, 200, 150, false), U256::from(1500));
+    }
+
+    #[test]
+    fn interpolate_amount_rounds_like_seaport() {
+        // (1000 * 2 + 0 * 1) / 3 = 666.67
+        let (start, end) = (U256::from(1000), U256::ZERO);
+        assert_eq!(interpolate_amount(start, end, 0, 3, 1, false), U256::from(666));
+        assert_eq!(interpolate_amount(start, end, 0, 3, 1, true), U256::from(667));
+
+        // exact results are not rounded up
+        assert_eq!(interpolate_amount(U256::from(3000), end, 0, 3, 1, true), U256::from(2000));
+        assert_eq!(interpolate_amount(U256::ZERO, U256::ZERO, 0, 3, 1, true), U256::ZERO);
+    }
+
+    #[test]
+    fn can_parse_units() {
+        assert_eq!(parse_units("1", 18), Some(U256::from(1_000_000_000_000_000_000u64)));
+        assert_eq!(parse_units("10.5", 18), Some(U256::from(10_500_000_000_000_000_000u128)));
+        assert_eq!(parse_units("0.0123", 18), Some(U256::from(12_300_000_000_000_000u64)));
+        assert_eq!(parse_units(".5", 1), Some(U256::from(5)));
+        assert_eq!(parse_units("1.23456789", 6), Some(U256::from(1_234_567)));
+        assert_eq!(parse_units("42", 0), Some(U256::from(42)));
+        assert_eq!(parse_units("", 18), None);
+        assert_eq!(parse_units(".", 18), None);
+        assert_eq!(parse_units("-1", 18), None);
+        assert_eq!(parse_units("1e18", 18), None);
+    }
+
+    #[test]
+    fn interpolate_amount_clamps_out_of_range() {
+        let (start, end) = (U256::from(2000), U256::from(1000));
+        assert_eq!(interpolate_amount(start, end, 100, 200, 0, false), start);
+        assert_eq!(interpolate_amount(start, end, 100, 200, 300, false), end);
+        assert_eq!(interpolate_amount(start, end, 200, 100, 150, false), end);
+        assert_eq!(interpolate_amount(start, end, 100, 100, 100, true), end);
+    }
+}
@@ -1,8 +1,17 @@
 pub mod api;
+pub mod seaport;
 
-use crate::types::api::{OpenSeaDetailedErrorCode, OpenSeaErrorResponse};
+use crate::{
+    constants::{
+        OPENSEA_CONDUIT_KEY, WAVAX_AVALANCHE, WAVAX_FUJI, WBNB_BSC, WETH_ARBITRUM, WETH_ARBITRUM_NOVA, WETH_ETHEREUM, WETH_GOERLI,
+        WETH_OP_STACK, WETH_SEPOLIA, WMATIC_MUMBAI, WMATIC_POLYGON,
+    },
+    types::api::{OpenSeaDetailedErrorCode, OpenSeaErrorResponse},
+};
+use alloy_primitives::{Address, B256};
+use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use std::{fmt, time::Duration};
 use strum::{AsRefStr, EnumString};
 use thiserror::Error;
 
@@ -17,10 +26,41 @@ pub enum OpenSeaApiError {
     OpenSeaError(#[from] OpenSeaErrorResponse),
     #[error(transparent)]
     OpenSeaDetailedError(#[from] OpenSeaDetailedErrorCode),
+    #[error("Rate limited, retry after {retry_after:?}")]
+    RateLimited { retry_after: Option<Duration> },
+    #[error("Unexpected status {status}: {body}")]
+    UnexpectedStatus { status: StatusCode, body: String },
+    #[error("Request cancelled, the client was shut down")]
+    Cancelled,
+    /// An error of a request for orders of a chain, with the chain the request was sent for.
+    #[error("{source} (chain {chain})")]
+    OnChain {
+        chain: Chain,
+        #[source]
+        source: Box<OpenSeaApiError>,
+    },
     #[error("{0}")]
     Other(String),
 }
 
+impl OpenSeaApiError {
+    /// Add the chain of the request as context, except for [`Self::Cancelled`] which does not depend on the chain.
+    pub(crate) fn on_chain(self, chain: &Chain) -> Self {
+        match self {
+            Self::Cancelled | Self::OnChain { .. } => self,
+            source => Self::OnChain { chain: chain.clone(), source: Box::new(source) },
+        }
+    }
+
+    /// The error without the chain added by [`Self::OnChain`], to match on the kind of the error.
+    pub fn without_chain(&self) -> &Self {
+        match self {
+            Self::OnChain { source, .. } => source,
+            _ => self,
+        }
+    }
+}
+
 /// API endpoints
 #[derive(Debug, Clone)]
 pub struct ApiUrl {
@@ -40,16 +80,59 @@ impl ApiUrl {
         format!("{}/listings/fulfillment_data", self.base)
     }
 
+    pub fn fulfill_offer(&self) -> String {
+        format!("{}/offers/fulfillment_data", self.base)
+    }
+
+    pub fn get_order(&self, chain: &Chain, protocol_address: &Address, order_hash: &B256) -> String {
+        format!("{}/orders/chain/{}/protocol/{}/{}", self.base, chain, protocol_address, order_hash)
+    }
+
+    pub fn cancel_order(&self, chain: &Chain, protocol_address: &Address, order_hash: &B256) -> String {
+        format!("{}/orders/chain/{}/protocol/{}/{}/cancel", self.base, chain, protocol_address, order_hash)
+    }
+
+    pub fn get_contract(&self, chain: &Chain, address: &Address) -> String {
+        format!("{}/chain/{}/contract/{}", self.base, chain, address)
+    }
+
+    pub fn get_account(&self, address_or_username: &str) -> String {
+        format!("{}/accounts/{}", self.base, address_or_username)
+    }
+
+    pub fn get_nfts_by_contract(&self, chain: &Chain, address: &Address) -> String {
+        format!("{}/chain/{}/contract/{}/nfts", self.base, chain, address)
+    }
+
+    pub fn get_nft(&self, chain: &Chain, address: &Address, identifier: &str) -> String {
+        format!("{}/chain/{}/contract/{}/nfts/{}", self.base, chain, address, identifier)
+    }
+
+    pub fn get_nft_events(&self, chain: &Chain, address: &Address, identifier: &str) -> String {
+        format!("{}/events/chain/{}/contract/{}/nfts/{}", self.base, chain, address, identifier)
+    }
+
+    pub fn get_collections(&self) -> String {
+        format!("{}/collections", self.base)
+    }
+
     pub fn get_collection(&self, collection_slug: String) -> String {
         format!("{}/collections/{}", self.base, collection_slug)
     }
-    pub fn get_all_listings(&self, collection_slug: String, query_parameters: String) -> String {
-        let url = format!("{}/listings/collection/{}/all", self.base, collection_slug);
-        if query_parameters.is_empty() {
-            url
-        } else {
-            format!("{}?{}", url, query_parameters)
-        }
+
+    pub fn get_collection_stats(&self, collection_slug: String) -> String {
+        format!("{}/collections/{}/stats", self.base, collection_slug)
+    }
+    pub fn get_collection_events(&self, collection_slug: String) -> String {
+        format!("{}/events/collection/{}", self.base, collection_slug)
+    }
+
+    pub fn get_all_listings(&self, collection_slug: String) -> String {
+        format!("{}/listings/collection/{}/all", self.base, collection_slug)
+    }
+
+    pub fn get_best_listing(&self, collection_slug: String, identifier: String) -> String {
+        format!("{}/listings/collection/{}/nfts/{}/best", self.base, collection_slug, identifier)
     }
 }
 
@@ -123,12 +206,70 @@ impl Chain {
     pub fn is_live_chain(&self) -> bool {
         !self.is_test_chain()
     }
+
+    /// The ERC20 wrapping the native currency of the chain, e.g. WETH on Ethereum. Offers on Seaport must be made in
+    /// this token as native currency cannot be pulled from the offerer. `None` if unknown or not an EVM chain.
+    pub fn wrapped_native_address(&self) -> Option<Address> {
+        use Chain::*;
+        match self {
+            Ethereum => Some(WETH_ETHEREUM),
+            Polygon => Some(WMATIC_POLYGON),
+            Base | Optimism | Zora | BaseGoerli | OptimismGoerli | ZoraTestnet => Some(WETH_OP_STACK),
+            Arbitrum => Some(WETH_ARBITRUM),
+            ArbitrumNova => Some(WETH_ARBITRUM_NOVA),
+            Avalanche => Some(WAVAX_AVALANCHE),
+            BSC => Some(WBNB_BSC),
+            Goerli => Some(WETH_GOERLI),
+            Sepolia => Some(WETH_SEPOLIA),
+            Mumbai => Some(WMATIC_MUMBAI),
+            AvalancheFuji => Some(WAVAX_FUJI),
+            Klaytn | Boabab | BSCTestnet | ArbitrumGoerli | Solana | SolanaDevnet => None,
+        }
+    }
+
+    /// The conduit key of the OpenSea conduit to use for the `conduit_key` of Seaport orders. `None` for chains
+    /// without Seaport.
+    pub fn opensea_conduit_key(&self) -> Option<B256> {
+        match self {
+            Chain::Solana | Chain::SolanaDevnet => None,
+            _ => Some(OPENSEA_CONDUIT_KEY),
+        }
+    }
+
+    /// The EIP-155 chain id, e.g. for the EIP-712 domain of Seaport orders. `None` for chains that are not EVM chains.
+    pub fn chain_id(&self) -> Option<u64> {
+        use Chain::*;
+        match self {
+            Ethereum => Some(1),
+            Polygon => Some(137),
+            Klaytn => Some(8217),
+            Base => Some(8453),
+            BSC => Some(56),
+            Arbitrum => Some(42161),
+            ArbitrumNova => Some(42170),
+            Avalanche => Some(43114),
+            Optimism => Some(10),
+            Zora => Some(7777777),
+            Goerli => Some(5),
+            Sepolia => Some(11155111),
+            Mumbai => Some(80001),
+            Boabab => Some(1001),
+            BaseGoerli => Some(84531),
+            BSCTestnet => Some(97),
+            ArbitrumGoerli => Some(421613),
+            AvalancheFuji => Some(43113),
+            OptimismGoerli => Some(420),
+            ZoraTestnet => Some(999),
+            Solana | SolanaDevnet => None,
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
     use serde_json::Value;
+    use std::str::FromStr;
 
     #[test]
     fn can_display_and_parse_chain() {
@@ -145,6 +286,35 @@ mod test {
         assert_eq!(chain, Chain::Polygon);
     }
 
+    #[test]
+    fn can_get_wrapped_native_address() {
+        assert_eq!(
+            Chain::Ethereum.wrapped_native_address(),
+            Some(Address::from_str("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2").unwrap())
+        );
+        assert_eq!(Chain::Polygon.wrapped_native_address(), Some(Address::from_str("0x0d500B1d8E8eF31E21C99d1Db9A6444d3ADf1270").unwrap()));
+        assert_eq!(Chain::Base.wrapped_native_address(), Chain::Optimism.wrapped_native_address());
+        assert!(Chain::Sepolia.wrapped_native_address().is_some());
+        assert_eq!(Chain::Solana.wrapped_native_address(), None);
+        assert_eq!(Chain::SolanaDevnet.wrapped_native_address(), None);
+    }
+
+    #[test]
+    fn can_get_opensea_conduit_key() {
+        let conduit_key = B256::from_str("0x0000007b02230091a7ed01230072f7006a004d60a8d4e71d599b8104250f0000").unwrap();
+        assert_eq!(Chain::Ethereum.opensea_conduit_key(), Some(conduit_key));
+        assert_eq!(Chain::Base.opensea_conduit_key(), Some(conduit_key));
+        assert_eq!(Chain::Solana.opensea_conduit_key(), None);
+    }
+
+    #[test]
+    fn can_get_chain_id() {
+        assert_eq!(Chain::Ethereum.chain_id(), Some(1));
+        assert_eq!(Chain::Polygon.chain_id(), Some(137));
+        assert_eq!(Chain::Sepolia.chain_id(), Some(11155111));
+        assert_eq!(Chain::Solana.chain_id(), None);
+    }
+
     #[test]
     fn can_serialize_chain() {
         let chain = Chain::Polygon;
@@ -1,8 +1,10 @@
+pub mod events;
+pub mod nfts;
 pub mod orders;
 
 use crate::{
-    constants::{SEAPORT_V1, SEAPORT_V4, SEAPORT_V5, SEAPORT_V6},
-    types::api::orders::ItemListing,
+    constants::{OPENSEA_FEE_RECIPIENT, SEAPORT_V1, SEAPORT_V4, SEAPORT_V5, SEAPORT_V6},
+    types::api::orders::{Currency, ItemListing, ItemType, OrderSide, Price, SeaportOrderParameters, SeaportProtocolData},
 };
 use alloy_primitives::{Address, Bytes, B256, U256};
 use chrono::{DateTime, NaiveDate, Utc};
@@ -12,22 +14,29 @@ use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::{Map, Number, Value};
 use serde_with::{serde_as, skip_serializing_none, TimestampSeconds};
 use std::{collections::HashMap, fmt, str::FromStr};
-use strum::Display;
+use strum::{Display, EnumString};
 use thiserror::Error;
 
 use super::{Chain, OpenSeaApiError};
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum OrderDirection {
     Asc,
+    #[default]
     Desc,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Sort keys of the `order_by` parameter of the V2 listings and offers endpoints ([`RetrieveListingsRequest`],
+/// [`RetrieveOffersRequest`]). These are the only keys supported by both endpoints, there is no separate
+/// listing time key. Other endpoints like the collection listings or events do not support sorting.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum OrderOpeningOption {
+    /// When the order was made. Together with `desc` this returns the most recently listed orders first.
+    #[default]
     CreatedDate,
+    /// The price converted to ETH. Only supported with `asset_contract_address` and `token_ids`.
     EthPrice,
 }
 
@@ -59,6 +68,171 @@ pub struct RetrieveListingsRequest {
     /// Only show orders listed before this timestamp. Seconds since the Unix epoch.
     #[serde_as(as = "Option<TimestampSeconds<i64>>")]
     pub listed_before: Option<DateTime<Utc>>,
+    /// Only show orders of this Seaport contract, e.g. to only get Seaport 1.6 orders.
+    pub protocol_address: Option<Address>,
+    /// The cursor of the page to retrieve, taken from `next` or `previous` of a previous response.
+    pub cursor: Option<Cursor>,
+    /// Additional query parameters not modeled by this request, appended to the query string verbatim.
+    #[serde(skip)]
+    pub extra_params: Vec<(String, String)>,
+}
+
+impl QueryRequest for RetrieveListingsRequest {
+    fn extra_params(&self) -> &[(String, String)] {
+        &self.extra_params
+    }
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct RetrieveOffersRequest {
+    /// Address of the contract for an NFT
+    pub asset_contract_address: Option<Address>,
+    /// Number of offers to retrieve
+    pub limit: Option<u8>,
+    /// An array of token IDs to search for (e.g. ?token_ids=1&token_ids=209).
+    pub token_ids: Vec<String>,
+    /// Filter by the order makers wallet address
+    pub maker: Option<Address>,
+    /// Filter by the order takers wallet address
+    pub taker: Option<Address>,
+    /// How to sort the orders.
+    pub order_by: Option<OrderOpeningOption>,
+    /// Can be asc or desc for ascending or descending sort.
+    pub order_direction: Option<OrderDirection>,
+    /// Only show orders listed after this timestamp. Seconds since the Unix epoch.
+    #[serde_as(as = "Option<TimestampSeconds<i64>>")]
+    pub listed_after: Option<DateTime<Utc>>,
+    /// Only show orders listed before this timestamp. Seconds since the Unix epoch.
+    #[serde_as(as = "Option<TimestampSeconds<i64>>")]
+    pub listed_before: Option<DateTime<Utc>>,
+    /// The cursor of the page to retrieve, taken from `next` or `previous` of a previous response.
+    pub cursor: Option<Cursor>,
+    /// Additional query parameters not modeled by this request, appended to the query string verbatim.
+    #[serde(skip)]
+    pub extra_params: Vec<(String, String)>,
+}
+
+impl QueryRequest for RetrieveOffersRequest {
+    fn extra_params(&self) -> &[(String, String)] {
+        &self.extra_params
+    }
+}
+
+impl RetrieveOffersRequest {
+    /// Builder validating the request on [`RetrieveOffersRequestBuilder::build`].
+    pub fn builder() -> RetrieveOffersRequestBuilder {
+        RetrieveOffersRequestBuilder::default()
+    }
+}
+
+/// Builder for [`RetrieveOffersRequest`].
+#[derive(Debug, Clone, Default)]
+pub struct RetrieveOffersRequestBuilder {
+    req: RetrieveOffersRequest,
+}
+
+impl RetrieveOffersRequestBuilder {
+    pub fn asset_contract_address(mut self, asset_contract_address: Address) -> Self {
+        self.req.asset_contract_address = Some(asset_contract_address);
+        self
+    }
+
+    pub fn limit(mut self, limit: u8) -> Self {
+        self.req.limit = Some(limit);
+        self
+    }
+
+    pub fn token_ids<I: IntoIterator<Item = S>, S: Into<String>>(mut self, token_ids: I) -> Self {
+        self.req.token_ids = token_ids.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn maker(mut self, maker: Address) -> Self {
+        self.req.maker = Some(maker);
+        self
+    }
+
+    pub fn taker(mut self, taker: Address) -> Self {
+        self.req.taker = Some(taker);
+        self
+    }
+
+    pub fn order_by(mut self, order_by: OrderOpeningOption) -> Self {
+        self.req.order_by = Some(order_by);
+        self
+    }
+
+    pub fn order_direction(mut self, order_direction: OrderDirection) -> Self {
+        self.req.order_direction = Some(order_direction);
+        self
+    }
+
+    pub fn listed_after(mut self, listed_after: DateTime<Utc>) -> Self {
+        self.req.listed_after = Some(listed_after);
+        self
+    }
+
+    /// Add a query parameter not modeled by the request, see [`RetrieveOffersRequest::extra_params`].
+    pub fn raw_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.req.extra_params.push((key.into(), value.into()));
+        self
+    }
+
+    pub fn listed_before(mut self, listed_before: DateTime<Utc>) -> Self {
+        self.req.listed_before = Some(listed_before);
+        self
+    }
+
+    pub fn cursor(mut self, cursor: impl Into<Cursor>) -> Self {
+        self.req.cursor = Some(cursor.into());
+        self
+    }
+
+    /// Build the request. Returns an error if ordering by `eth_price` without a contract address, or if
+    /// `listed_after` is later than `listed_before`, as OpenSea silently returns no orders for these.
+    pub fn build(self) -> Result<RetrieveOffersRequest, OpenSeaApiError> {
+        if self.req.order_by == Some(OrderOpeningOption::EthPrice) && self.req.asset_contract_address.is_none() {
+            return Err(OpenSeaApiError::Other("Ordering by eth_price requires an asset_contract_address".to_string()));
+        }
+        if let (Some(listed_after), Some(listed_before)) = (self.req.listed_after, self.req.listed_before) {
+            if listed_after > listed_before {
+                return Err(OpenSeaApiError::Other(format!("listed_after {listed_after} is after listed_before {listed_before}")));
+            }
+        }
+        Ok(self.req)
+    }
+}
+
+/// Opaque cursor of a page of results. Taken from `next` or `previous` of a response and passed as cursor of the
+/// following request, serialized as the raw string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Cursor(pub String);
+
+impl Cursor {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Cursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for Cursor {
+    fn from(cursor: String) -> Self {
+        Cursor(cursor)
+    }
+}
+
+impl From<&str> for Cursor {
+    fn from(cursor: &str) -> Self {
+        Cursor(cursor.to_string())
+    }
 }
 
 #[serde_as]
@@ -66,7 +240,16 @@ pub struct RetrieveListingsRequest {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct GetAllListingsRequest {
     pub limit: Option<u8>,
-    pub next: Option<String>,
+    pub next: Option<Cursor>,
+    /// Additional query parameters not modeled by this request, appended to the query string verbatim.
+    #[serde(skip)]
+    pub extra_params: Vec<(String, String)>,
+}
+
+impl QueryRequest for GetAllListingsRequest {
+    fn extra_params(&self) -> &[(String, String)] {
+        &self.extra_params
+    }
 }
 
 pub(crate) fn value_to_string(v: &Value) -> Result<String, OpenSeaApiError> {
@@ -78,29 +261,108 @@ pub(crate) fn value_to_string(v: &Value) -> Result<String, OpenSeaApiError> {
     }
 }
 
-impl RetrieveListingsRequest {
-    /// Converts RetrieveListingsRequest into serde_json::Map<String, serde_json::Value>
-    pub fn to_map(&self) -> serde_json::Result<Map<String, Value>> {
-        Ok(serde_json::to_value(self)?.as_object().expect("This should never happen").to_owned())
+/// A request sent as query string, with the parameters not modeled by the request appended to the typed ones.
+pub(crate) trait QueryRequest: Serialize + Sized {
+    /// Additional query parameters of the request, appended as they are.
+    fn extra_params(&self) -> &[(String, String)];
+
+    /// The typed parameters of the request converted by [`to_query_pairs`], followed by its extra parameters.
+    fn query_pairs(&self) -> Result<Vec<(String, String)>, OpenSeaApiError> {
+        let mut pairs = to_query_pairs(self)?;
+        pairs.extend_from_slice(self.extra_params());
+        Ok(pairs)
     }
+}
 
-    /// Converts RetrieveListingsRequest into a vector of key-value pairs
-    /// OpenSea API expects arrays to be passed as a sequence of parameters with the same key (e.g. ?token_ids=1&token_ids=209)
-    /// https://github.com/ProjectOpenSea/opensea-js/blob/893866a7381ec455814be2ac9943d45ee38da58f/src/api/api.ts#L673C11-L673C31
-    pub fn to_qs_vec(&self) -> Result<Vec<(String, String)>, OpenSeaApiError> {
-        let map = self.to_map()?;
-        let mut vec = Vec::new();
+/// Converts a request into a vector of query key-value pairs.
+/// OpenSea API expects arrays to be passed as a sequence of parameters with the same key (e.g. ?token_ids=1&token_ids=209)
+/// https://github.com/ProjectOpenSea/opensea-js/blob/893866a7381ec455814be2ac9943d45ee38da58f/src/api/api.ts#L673C11-L673C31
+/// Null values are skipped and the keys of nested objects are flattened into the top level.
+pub(crate) fn to_query_pairs<T: Serialize>(req: &T) -> Result<Vec<(String, String)>, OpenSeaApiError> {
+    fn push_pairs(map: &Map<String, Value>, vec: &mut Vec<(String, String)>) -> Result<(), OpenSeaApiError> {
         for (k, v) in map.iter() {
             match v {
+                Value::Null => {}
                 Value::Array(arr) => {
-                    for v in arr {
-                        vec.push((k.clone(), value_to_string(v)?))
+                    for v in arr.iter().filter(|v| !v.is_null()) {
+                        match v {
+                            Value::Array(pair) if pair.len() == 2 => vec.push((value_to_string(&pair[0])?, value_to_string(&pair[1])?)),
+                            _ => vec.push((k.clone(), value_to_string(v)?)),
+                        }
                     }
                 }
+                Value::Object(map) => push_pairs(map, vec)?,
                 _ => vec.push((k.clone(), value_to_string(v)?)),
             }
         }
-        Ok(vec)
+        Ok(())
+    }
+
+    let value = serde_json::to_value(req)?;
+    let Value::Object(ref map) = value else {
+        return Err(OpenSeaApiError::Other(format!("Query must be an object: {value:?}")));
+    };
+    let mut vec = Vec::new();
+    push_pairs(map, &mut vec)?;
+    Ok(vec)
+}
+
+impl RetrieveListingsRequest {
+    /// Request for the listings of a single NFT. Ordering by `eth_price` is supported for this request.
+    pub fn for_token(contract: Address, token_id: impl Into<String>) -> Self {
+        Self { asset_contract_address: Some(contract), token_ids: vec![token_id.into()], ..Default::default() }
+    }
+
+    /// Sort the listings by their price in ETH ascending, i.e. the cheapest listing first. Like
+    /// [`OrderOpeningOption::EthPrice`] this requires `asset_contract_address` and `token_ids` to be set.
+    pub fn cheapest_first(self) -> Self {
+        Self { order_by: Some(OrderOpeningOption::EthPrice), order_direction: Some(OrderDirection::Asc), ..self }
+    }
+
+    /// Converts RetrieveListingsRequest into serde_json::Map<String, serde_json::Value>
+    pub fn to_map(&self) -> serde_json::Result<Map<String, Value>> {
+        Ok(serde_json::to_value(self)?.as_object().expect("This should never happen").to_owned())
+    }
+
+    /// Converts RetrieveListingsRequest into a vector of key-value pairs
+    pub fn to_qs_vec(&self) -> Result<Vec<(String, String)>, OpenSeaApiError> {
+        self.query_pairs()
+    }
+
+    /// Split the request into requests for consecutive chunks of `token_ids`, each with a query string of at most
+    /// `max_query_len` characters. Returns the request unchanged if it already fits. Fails if a request with a
+    /// single token id is still too long or a paginated request would have to be split, as the cursor is only valid
+    /// for the original token ids.
+    pub fn split_token_ids(&self, max_query_len: usize) -> Result<Vec<Self>, OpenSeaApiError> {
+        let pair_len = |key: &str, value: &str| key.len() + value.len() + 2;
+        let pairs = self.query_pairs()?;
+        if pairs.iter().map(|(key, value)| pair_len(key, value)).sum::<usize>() <= max_query_len {
+            return Ok(vec![self.clone()]);
+        }
+        if self.cursor.is_some() {
+            return Err(OpenSeaApiError::Other(format!(
+                "Query exceeds {max_query_len} characters, request fewer token_ids per page instead of paginating with a cursor"
+            )));
+        }
+
+        let base_len: usize = pairs.iter().filter(|(key, _)| key != "token_ids").map(|(key, value)| pair_len(key, value)).sum();
+        let mut requests = Vec::new();
+        let mut chunk = Vec::new();
+        let mut len = base_len;
+        for token_id in &self.token_ids {
+            let token_len = pair_len("token_ids", token_id);
+            if base_len + token_len > max_query_len {
+                return Err(OpenSeaApiError::Other(format!("Query exceeds {max_query_len} characters even for token id {token_id}")));
+            }
+            if len + token_len > max_query_len {
+                requests.push(Self { token_ids: std::mem::take(&mut chunk), ..self.clone() });
+                len = base_len;
+            }
+            chunk.push(token_id.clone());
+            len += token_len;
+        }
+        requests.push(Self { token_ids: chunk, ..self.clone() });
+        Ok(requests)
     }
 }
 
@@ -110,21 +372,84 @@ impl RetrieveListingsRequest {
 /// Properties:
 ///
 /// * `next`: An optional string that represents the cursor of the next page of listings. If there is no
-///    next page, this field will be None.
+///   next page, this field will be None.
 /// * `previous`: The `previous` property is an optional string that represents the cursor of the previous
-///    page of listings. If there is no previous page, the value will be `None`.
+///   page of listings. If there is no previous page, the value will be `None`.
 /// * `orders`: The `orders` property is a vector (or array) of `Order` structs. It represents a list of orders.
+/// * `chain`: The chain the listings were retrieved from, set by the client. Not part of the API response.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct RetrieveListingsResponse {
-    pub next: Option<String>,
-    pub previous: Option<String>,
+    pub next: Option<Cursor>,
+    pub previous: Option<Cursor>,
+    #[serde(default, deserialize_with = "vec_from_null")]
+    pub orders: Vec<Order>,
+    /// The `asset_contract_address` filter is not checked against the chain, a contract of another chain simply
+    /// returns no orders. The chain is kept here to make such empty responses inspectable.
+    #[serde(skip)]
+    pub chain: Option<Chain>,
+}
+
+impl RetrieveListingsResponse {
+    /// Returns the order with the lowest `current_price`. Orders with an unparsable price are ignored.
+    pub fn cheapest(&self) -> Option<&Order> {
+        self.orders
+            .iter()
+            .filter_map(|order| Some((order.current_price_u256()?, order)))
+            .min_by_key(|(price, _)| *price)
+            .map(|(_, order)| order)
+    }
+
+    /// Returns the orders with a `current_price` within the inclusive bounds. Orders with an unparsable price are
+    /// excluded.
+    pub fn filter_price_range(&self, min: Option<U256>, max: Option<U256>) -> Vec<Order> {
+        self.orders
+            .iter()
+            .filter(|order| {
+                order.current_price_u256().is_some_and(|price| min.is_none_or(|min| price >= min) && max.is_none_or(|max| price <= max))
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Sorts the orders by `current_price` ascending. Orders with an unparsable price are moved to the end.
+    pub fn sort_by_price(&mut self) {
+        self.orders.sort_by_cached_key(|order| order.current_price_u256().map_or((1, U256::ZERO), |price| (0, price)));
+    }
+}
+
+/// Orders of a paginated retrieval that may have stopped early, see
+/// [`OpenSeaV2Client::retrieve_all_listings_partial`](crate::OpenSeaV2Client::retrieve_all_listings_partial).
+#[derive(Debug)]
+pub struct PartialResult {
+    /// Orders of all pages fetched successfully, de-duplicated by order hash.
+    pub orders: Vec<Order>,
+    /// The error of the page that failed, `None` if all pages were fetched.
+    pub error: Option<OpenSeaApiError>,
+    /// Number of pages fetched successfully.
+    pub pages_fetched: usize,
+}
+
+/// Response from OpenSea retrieve offers endpoint containing a list of orders, along with
+/// optional pagination cursors.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct RetrieveOffersResponse {
+    pub next: Option<Cursor>,
+    pub previous: Option<Cursor>,
+    #[serde(default, deserialize_with = "vec_from_null")]
     pub orders: Vec<Order>,
+    /// The chain the offers were retrieved from, set by the client like [`RetrieveListingsResponse::chain`].
+    #[serde(skip)]
+    pub chain: Option<Chain>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct GetAllListingsResponse {
+    #[serde(default, deserialize_with = "vec_from_null")]
     pub listings: Vec<ItemListing>,
-    pub next: Option<String>,
+    pub next: Option<Cursor>,
 }
 
 /// Request to fulfill a listing on OpenSea.
@@ -134,6 +459,57 @@ pub struct FulfillListingRequest {
     pub fulfiller: Fulfiller,
 }
 
+impl FulfillListingRequest {
+    /// Builder checking all required fields are set on [`FulfillListingRequestBuilder::build`].
+    pub fn builder() -> FulfillListingRequestBuilder {
+        FulfillListingRequestBuilder::default()
+    }
+}
+
+/// Builder for [`FulfillListingRequest`].
+#[derive(Debug, Clone, Default)]
+pub struct FulfillListingRequestBuilder {
+    order_hash: Option<B256>,
+    chain: Option<Chain>,
+    protocol_version: Option<ProtocolVersion>,
+    fulfiller: Option<Address>,
+}
+
+impl FulfillListingRequestBuilder {
+    pub fn order_hash(mut self, order_hash: B256) -> Self {
+        self.order_hash = Some(order_hash);
+        self
+    }
+
+    pub fn chain(mut self, chain: Chain) -> Self {
+        self.chain = Some(chain);
+        self
+    }
+
+    pub fn protocol_version(mut self, protocol_version: ProtocolVersion) -> Self {
+        self.protocol_version = Some(protocol_version);
+        self
+    }
+
+    pub fn fulfiller(mut self, fulfiller: Address) -> Self {
+        self.fulfiller = Some(fulfiller);
+        self
+    }
+
+    /// Build the request. Returns an error naming the first missing field, all fields are required.
+    pub fn build(self) -> Result<FulfillListingRequest, OpenSeaApiError> {
+        let missing = |field: &str| OpenSeaApiError::Other(format!("Missing {field} of the listing to fulfill"));
+        Ok(FulfillListingRequest {
+            listing: Listing {
+                hash: self.order_hash.ok_or_else(|| missing("order_hash"))?,
+                chain: self.chain.ok_or_else(|| missing("chain"))?,
+                protocol_version: self.protocol_version.ok_or_else(|| missing("protocol_version"))?,
+            },
+            fulfiller: Fulfiller { address: self.fulfiller.ok_or_else(|| missing("fulfiller"))? },
+        })
+    }
+}
+
 /// Listing we want to fulfill on OpenSea.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Listing {
@@ -149,15 +525,136 @@ pub struct Fulfiller {
     pub address: Address,
 }
 
+/// Request to fulfill an offer on OpenSea, i.e. to accept a bid by selling an NFT.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FulfillOfferRequest {
+    /// The offer to accept, identified by its hash, chain and protocol like a listing.
+    pub offer: Listing,
+    pub fulfiller: Fulfiller,
+    pub consideration: ConsiderationInput,
+}
+
+impl FulfillOfferRequest {
+    /// Create the request to accept a retrieved offer by selling the given NFT. Returns an error if the order is
+    /// not an offer, has no hash or unknown protocol, or does not ask for a token of the NFT's contract.
+    pub fn from_order(order: &Order, chain: Chain, fulfiller: Address, consideration: ConsiderationInput) -> Result<Self, OpenSeaApiError> {
+        if order.side != OrderSide::Bid {
+            return Err(OpenSeaApiError::Other(format!("Order is a {:?} and not an offer", order.side)));
+        }
+        let order_hash = order.order_hash.as_deref().ok_or_else(|| OpenSeaApiError::Other("Offer has no order hash".to_string()))?;
+        let hash = B256::from_str(order_hash).map_err(|e| OpenSeaApiError::Other(format!("Invalid order hash {order_hash:?}: {e}")))?;
+        let protocol_version = order.protocol_address.as_deref().and_then(protocol_version_from_address).ok_or_else(|| {
+            OpenSeaApiError::Other(format!("Unknown protocol address {:?} of offer {order_hash}", order.protocol_address))
+        })?;
+
+        let asks_for_contract = order.protocol_data.parameters.consideration.iter().any(|item| {
+            !matches!(item.item_type, ItemType::Native | ItemType::ERC20)
+                && Address::from_str(&item.token).is_ok_and(|token| token == consideration.asset_contract_address)
+        });
+        if !asks_for_contract {
+            return Err(OpenSeaApiError::Other(format!(
+                "Offer {order_hash} does not ask for a token of contract {}",
+                consideration.asset_contract_address
+            )));
+        }
+
+        Ok(Self { offer: Listing { hash, chain, protocol_version }, fulfiller: Fulfiller { address: fulfiller }, consideration })
+    }
+}
+
+/// The NFT sold to fulfill an offer.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ConsiderationInput {
+    pub asset_contract_address: Address,
+    pub token_id: String,
+}
+
+/// A signed Seaport order to post as a listing or offer.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PostOrderRequest {
+    pub parameters: SeaportOrderParameters,
+    /// The signature of the order as hex string.
+    pub signature: String,
+    pub protocol_address: Address,
+}
+
+/// Check an ECDSA signature of a Seaport order is a hex encoded 65 byte `r || s || v` signature or a 64 byte EIP-2098
+/// compact signature. A bulk order signature is followed by a 3 byte order index and a proof of 1 to 24 32 byte
+/// hashes. OpenSea rejects malformed signatures with an opaque 400, so this can be checked before posting an order of
+/// an EOA. It is not applied when posting, as EIP-1271 signatures of contract wallets can have any length.
+pub fn validate_seaport_signature(sig: &str) -> Result<(), OpenSeaApiError> {
+    let bytes = Bytes::from_str(sig).map_err(|e| OpenSeaApiError::Other(format!("Signature {sig:?} is not valid hex: {e}")))?;
+    let is_bulk = |sig_len: usize| {
+        bytes.len().checked_sub(sig_len + 3).is_some_and(|proof_len| proof_len % 32 == 0 && (1..=24).contains(&(proof_len / 32)))
+    };
+    let sig_len = match bytes.len() {
+        _ if is_bulk(64) => 64,
+        _ if is_bulk(65) => 65,
+        len => len,
+    };
+    match sig_len {
+        64 => Ok(()),
+        65 if matches!(bytes[64], 0 | 1 | 27 | 28) => Ok(()),
+        65 => Err(OpenSeaApiError::Other(format!("Signature has invalid recovery id {}, expected 0, 1, 27 or 28", bytes[64]))),
+        len => Err(OpenSeaApiError::Other(format!(
+            "Signature has {len} bytes, expected 65 or 64 (EIP-2098 compact) optionally followed by a bulk order proof"
+        ))),
+    }
+}
+
+/// Response from OpenSea when posting a listing or offer.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct PostOrderResponse {
+    pub order: Order,
+}
+
+/// Response from OpenSea when retrieving a single order.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct GetOrderResponse {
+    pub order: Order,
+}
+
+/// Request to cancel an order offchain.
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
+pub struct CancelOrderRequest {
+    /// Signature of the offerer authorizing the cancellation. Not needed if the request is sent with the API key of
+    /// the offerer.
+    pub offerer_signature: Option<String>,
+}
+
+/// Response from OpenSea when cancelling an order.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct CancelOrderResponse {
+    /// Until when previously issued fulfillment signatures of the order stay valid.
+    pub last_signature_issued_valid_until: Option<String>,
+}
+
 /// Response from OpenSea fulfill listing endpoint.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct FulfillListingResponse {
     pub protocol: String,
     pub fulfillment_data: FulfillmentData,
 }
 
+impl FulfillListingResponse {
+    /// Parses the `protocol` of the response, returns `None` for an unknown protocol.
+    pub fn protocol_version(&self) -> Option<ProtocolVersion> {
+        self.protocol.parse::<SeaportProtocol>().ok().map(Into::into)
+    }
+
+    /// Returns true if the listing is fulfilled by sending a transaction, false if OpenSea returned only signed orders.
+    pub fn is_onchain(&self) -> bool {
+        matches!(self.fulfillment_data, FulfillmentData::Transaction { .. })
+    }
+}
+
 /// Protocol version for the listing.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum ProtocolVersion {
     V1_1,
     V1_4,
@@ -165,31 +662,165 @@ pub enum ProtocolVersion {
     V1_6,
 }
 
-/// Information needed to fulfill the listing.
+/// Seaport protocol as named in OpenSea responses e.g. `seaport1.6`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, EnumString, Display)]
+pub enum SeaportProtocol {
+    #[serde(rename = "seaport1.1")]
+    #[strum(serialize = "seaport1.1")]
+    Seaport1_1,
+    #[serde(rename = "seaport1.4")]
+    #[strum(serialize = "seaport1.4")]
+    Seaport1_4,
+    #[serde(rename = "seaport1.5")]
+    #[strum(serialize = "seaport1.5")]
+    Seaport1_5,
+    #[serde(rename = "seaport1.6")]
+    #[strum(serialize = "seaport1.6")]
+    Seaport1_6,
+}
+
+impl From<SeaportProtocol> for ProtocolVersion {
+    fn from(protocol: SeaportProtocol) -> Self {
+        match protocol {
+            SeaportProtocol::Seaport1_1 => ProtocolVersion::V1_1,
+            SeaportProtocol::Seaport1_4 => ProtocolVersion::V1_4,
+            SeaportProtocol::Seaport1_5 => ProtocolVersion::V1_5,
+            SeaportProtocol::Seaport1_6 => ProtocolVersion::V1_6,
+        }
+    }
+}
+
+/// Information needed to fulfill the listing. OpenSea usually returns a transaction to send onchain, but may return
+/// only the signed orders e.g. for orders fulfilled by a relayer.
 #[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct FulfillmentData {
-    pub transaction: Transaction,
+#[serde(untagged, try_from = "RawFulfillmentData")]
+pub enum FulfillmentData {
+    /// Transaction fulfilling the listing onchain.
+    Transaction { transaction: Box<Transaction> },
+    /// Signed orders without a transaction.
+    Signature { orders: Vec<SeaportProtocolData> },
+}
+
+impl FulfillmentData {
+    /// The transaction to send, `None` for signature based fulfillment.
+    pub fn transaction(&self) -> Option<&Transaction> {
+        match self {
+            FulfillmentData::Transaction { transaction } => Some(transaction),
+            FulfillmentData::Signature { .. } => None,
+        }
+    }
+}
+
+/// Fulfillment data as returned by OpenSea, the form is decided by the presence of the transaction.
+#[derive(Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+struct RawFulfillmentData {
+    transaction: Option<Transaction>,
+    #[serde(default)]
+    orders: Vec<SeaportProtocolData>,
+}
+
+impl TryFrom<RawFulfillmentData> for FulfillmentData {
+    type Error = String;
+
+    fn try_from(raw: RawFulfillmentData) -> Result<Self, Self::Error> {
+        match raw.transaction {
+            Some(transaction) => Ok(FulfillmentData::Transaction { transaction: Box::new(transaction) }),
+            None if !raw.orders.is_empty() => Ok(FulfillmentData::Signature { orders: raw.orders }),
+            None => Err("Fulfillment data contains neither a transaction nor orders".to_string()),
+        }
+    }
 }
 
 /// Transaction data for onchain fulfillment.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "RawTransaction")]
 pub struct Transaction {
     pub function: String,
     pub chain: u64,
     pub to: String,
-    #[serde(deserialize_with = "u256_from_dec", serialize_with = "u256_to_dec")]
+    /// Serialized as decimal string, deserialized from a decimal number or string.
+    #[serde(deserialize_with = "u256_from_dec", serialize_with = "u256_to_dec_str")]
     pub value: U256,
     pub input_data: InputData,
 }
 
+/// Transaction as returned by OpenSea, the shape of the input data depends on the function.
+#[derive(Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+struct RawTransaction {
+    function: String,
+    chain: u64,
+    to: String,
+    #[serde(deserialize_with = "u256_from_dec")]
+    value: U256,
+    input_data: Value,
+}
+
+impl TryFrom<RawTransaction> for Transaction {
+    type Error = serde_json::Error;
+
+    fn try_from(raw: RawTransaction) -> Result<Self, Self::Error> {
+        let input_data = if raw.function.starts_with("fulfillAdvancedOrder") {
+            InputData::Advanced(serde_json::from_value(raw.input_data)?)
+        } else {
+            InputData::Basic { parameters: serde_json::from_value(raw.input_data["parameters"].clone())? }
+        };
+        Ok(Transaction { function: raw.function, chain: raw.chain, to: raw.to, value: raw.value, input_data })
+    }
+}
+
 /// Additional input data for the transaction.
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum InputData {
+    /// Input for `fulfillBasicOrder`, used for simple listings.
+    Basic { parameters: Parameters },
+    /// Input for `fulfillAdvancedOrder`, used e.g. for English auctions and criteria orders.
+    Advanced(AdvancedOrderParameters),
+}
+
+/// Parameters for onchain fulfillment of an advanced order.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct AdvancedOrderParameters {
+    pub advanced_order: AdvancedOrder,
+    pub criteria_resolvers: Vec<CriteriaResolver>,
+    pub fulfiller_conduit_key: B256,
+    pub recipient: Address,
+}
+
+/// Seaport advanced order, which supports partial fills and extra data.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct AdvancedOrder {
+    pub parameters: SeaportOrderParameters,
+    pub numerator: u128,
+    pub denominator: u128,
+    #[serde(deserialize_with = "bytes_from_str")]
+    pub signature: Bytes,
+    #[serde(deserialize_with = "bytes_from_str")]
+    pub extra_data: Bytes,
+}
+
+/// Resolves a criteria item of an order to a specific token identifier.
 #[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct InputData {
-    pub parameters: Parameters,
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct CriteriaResolver {
+    pub order_index: u64,
+    pub side: u8,
+    pub index: u64,
+    #[serde(deserialize_with = "u256_from_dec_str", serialize_with = "u256_to_dec_str")]
+    pub identifier: U256,
+    pub criteria_proof: Vec<B256>,
 }
 
 /// Parameters for onchain transaction fulfillment.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 #[serde(rename_all = "camelCase")]
 pub struct Parameters {
     pub consideration_token: Address,
@@ -223,6 +854,7 @@ pub struct Parameters {
 
 /// Additional recipient for onchain transaction fulfillment.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct AdditionalRecipient {
     #[serde(deserialize_with = "u256_from_dec_str", serialize_with = "u256_to_dec_str")]
     pub amount: U256,
@@ -240,6 +872,19 @@ pub(crate) fn protocol_version_to_str<S: Serializer>(protocol_version: &Protocol
     serializer.serialize_str(protocol_version_str)
 }
 
+/// The protocol version of a Seaport contract address, `None` for an unknown address.
+pub(crate) fn protocol_version_from_address(address: &str) -> Option<ProtocolVersion> {
+    [
+        (SEAPORT_V1, ProtocolVersion::V1_1),
+        (SEAPORT_V4, ProtocolVersion::V1_4),
+        (SEAPORT_V5, ProtocolVersion::V1_5),
+        (SEAPORT_V6, ProtocolVersion::V1_6),
+    ]
+    .into_iter()
+    .find(|(seaport, _)| seaport.eq_ignore_ascii_case(address))
+    .map(|(_, protocol_version)| protocol_version)
+}
+
 /// Helper function to convert a string to bytes.
 pub(crate) fn bytes_from_str<'de, D>(deserializer: D) -> Result<Bytes, D::Error>
 where
@@ -249,6 +894,33 @@ where
     Bytes::from_str(&val).map_err(de::Error::custom)
 }
 
+/// Helper function to convert a string to an address, accepting checksummed and lowercase input.
+pub(crate) fn address_from_str<'de, D>(deserializer: D) -> Result<Address, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let val = String::deserialize(deserializer)?;
+    Address::from_str(val.trim()).map_err(de::Error::custom)
+}
+
+/// Helper function to convert an optional string to an address, mapping null and an empty string to `None`.
+pub(crate) fn option_address_from_str<'de, D>(deserializer: D) -> Result<Option<Address>, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let val = Option::<String>::deserialize(deserializer)?;
+    val.as_deref().map(str::trim).filter(|val| !val.is_empty()).map(|val| Address::from_str(val).map_err(de::Error::custom)).transpose()
+}
+
+/// Helper function to convert a list of strings to addresses, accepting checksummed and lowercase input and null.
+pub(crate) fn addresses_from_str<'de, D>(deserializer: D) -> Result<Vec<Address>, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let val = Option::<Vec<String>>::deserialize(deserializer)?.unwrap_or_default();
+    val.iter().map(|address| Address::from_str(address.trim()).map_err(de::Error::custom)).collect()
+}
+
 /// Helper function to convert a decimal string to a U256.
 pub(crate) fn u256_from_dec_str<'de, D>(deserializer: D) -> Result<U256, D::Error>
 where
@@ -267,34 +939,74 @@ where
     serializer.serialize_str(decimal_str.as_str())
 }
 
-/// Helper function to convert a decimal to a U256.
-pub(crate) fn u256_from_dec<'de, D>(deserializer: D) -> Result<U256, D::Error>
+/// Helper function to convert a hex string with or without `0x` prefix to a B256. Shorter hex strings are
+/// left-padded with zeros, e.g. `0x0` for an empty zone hash.
+pub(crate) fn b256_from_str<'de, D>(deserializer: D) -> Result<B256, D::Error>
 where
     D: de::Deserializer<'de>,
 {
-    let val = Number::deserialize(deserializer)?;
-    U256::from_str(val.as_str()).map_err(de::Error::custom)
+    let val = String::deserialize(deserializer)?;
+    let hex = val.strip_prefix("0x").unwrap_or(&val);
+    if hex.len() > 64 {
+        return Err(de::Error::custom(format!("hex string {val:?} is longer than 32 bytes")));
+    }
+    B256::from_str(&format!("{hex:0>64}")).map_err(de::Error::custom)
 }
 
-/// Helper function to convert a U256 to decimal.
-pub(crate) fn u256_to_dec<S>(value: &U256, serializer: S) -> Result<S::Ok, S::Error>
+/// Helper function to convert a number or a decimal or hex string to a U256.
+pub(crate) fn u256_from_dec<'de, D>(deserializer: D) -> Result<U256, D::Error>
 where
-    S: Serializer,
+    D: de::Deserializer<'de>,
 {
-    if value <= &U256::from(u128::MAX) {
-        serializer.serialize_u128(value.to::<u128>())
-    } else {
-        use serde::ser::Error;
-        Err(S::Error::custom("U256 value is too large for u128"))
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Decimal {
+        Number(Number),
+        String(String),
     }
-}
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Account {
-    pub user: Option<UserId>,
-    pub profile_img_url: String,
-    pub address: String,
-    pub config: String,
+    match Decimal::deserialize(deserializer)? {
+        Decimal::Number(val) => U256::from_str(val.as_str()).map_err(de::Error::custom),
+        Decimal::String(val) => U256::from_str(&val).map_err(de::Error::custom),
+    }
+}
+
+/// Helper function to deserialize a decimal given as number or string to its string representation. Numbers are kept
+/// exact, also if they do not fit into a u64.
+pub(crate) fn string_from_str_or_number<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Decimal {
+        Number(Number),
+        String(String),
+    }
+
+    match Decimal::deserialize(deserializer)? {
+        Decimal::Number(val) => Ok(val.to_string()),
+        Decimal::String(val) => Ok(val),
+    }
+}
+
+/// Helper function to deserialize a list given as null, e.g. during OpenSea outages, to an empty list.
+pub(crate) fn vec_from_null<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: de::Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Ok(Option::<Vec<T>>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Account {
+    pub user: Option<UserId>,
+    pub profile_img_url: String,
+    #[serde(deserialize_with = "address_from_str")]
+    pub address: Address,
+    pub config: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
@@ -337,6 +1049,36 @@ impl<'de> Deserialize<'de> for UserId {
 pub enum OpenSeaDetailedErrorCode {
     OrderHashDoesNotExist,
     OrderCannotBeFulfilled,
+    /// The maker or fulfiller does not have enough balance or approvals for the order.
+    InsufficientBalance,
+    OrderExpired,
+    /// The listing was cancelled, filled or is not yet active.
+    ListingNotActive,
+    InvalidSignature,
+}
+
+impl OpenSeaDetailedErrorCode {
+    /// Map an error message of OpenSea to its error code, `None` for unknown messages. Exact messages are matched
+    /// first, then known phrases case-insensitively as OpenSea includes details like the order hash in some messages.
+    pub fn from_message(message: &str) -> Option<Self> {
+        match message {
+            "The order_hash you provided does not exist" => return Some(Self::OrderHashDoesNotExist),
+            "This order can not be fulfilled at this time." => return Some(Self::OrderCannotBeFulfilled),
+            _ => {}
+        }
+        let message = message.to_lowercase();
+        let phrases = [
+            ("insufficient balance", Self::InsufficientBalance),
+            ("insufficient funds", Self::InsufficientBalance),
+            ("order has expired", Self::OrderExpired),
+            ("order is expired", Self::OrderExpired),
+            ("listing is not active", Self::ListingNotActive),
+            ("listing not active", Self::ListingNotActive),
+            ("invalid signature", Self::InvalidSignature),
+            ("signature is invalid", Self::InvalidSignature),
+        ];
+        phrases.into_iter().find(|(phrase, _)| message.contains(phrase)).map(|(_, code)| code)
+    }
 }
 
 #[derive(Error, Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -350,7 +1092,7 @@ impl fmt::Display for OpenSeaErrorResponse {
     }
 }
 
-#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum SafelistStatus {
     NotRequested,
@@ -359,13 +1101,35 @@ pub enum SafelistStatus {
     Verified,
     DisabledTopTrending,
 }
+
+impl SafelistStatus {
+    /// Whether the collection is verified by OpenSea, shown with a blue checkmark.
+    pub fn is_verified(&self) -> bool {
+        matches!(self, SafelistStatus::Verified)
+    }
+
+    /// Whether the collection is approved or verified by OpenSea.
+    pub fn is_trusted(&self) -> bool {
+        matches!(self, SafelistStatus::Approved | SafelistStatus::Verified)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct CollectionFee {
     pub fee: f64,
-    pub recipient: String,
+    #[serde(deserialize_with = "address_from_str")]
+    pub recipient: Address,
     pub required: Option<bool>,
 }
 
+impl CollectionFee {
+    /// The fee in basis points, the fee is given as a percentage e.g. `2.5` is `250` basis points.
+    pub fn basis_points(&self) -> u64 {
+        (self.fee * 100.0).round() as u64
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum RarityStrategy {
@@ -373,6 +1137,7 @@ pub enum RarityStrategy {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct CollectionRarity {
     pub strategy_id: RarityStrategy,
     pub strategy_version: String,
@@ -382,9 +1147,12 @@ pub struct CollectionRarity {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct PaymentToken {
     pub symbol: String,
     pub address: String,
+    /// The chain name as returned by OpenSea, which may be a chain not known to [`Chain`]. Use
+    /// [`PaymentToken::parsed_chain`] for the parsed value.
     pub chain: String,
     pub image: Option<String>, // doc is wrong here e.g. snout-bears-nft
     pub name: Option<String>,  // same
@@ -393,14 +1161,25 @@ pub struct PaymentToken {
     pub usd_price: String,
 }
 
+impl PaymentToken {
+    /// The chain of the token, accepting the aliases of [`Chain`], e.g. `matic` and `polygon`. `None` if the chain is
+    /// not known to [`Chain`], e.g. `blast`.
+    pub fn parsed_chain(&self) -> Option<Chain> {
+        Chain::from_str(&self.chain).ok()
+    }
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct CollectionResponse {
     pub collection: String,
     pub name: String,
     pub description: Option<String>,
     pub image_url: Option<String>,
     pub banner_image_url: Option<String>,
-    pub owner: String,
+    /// The owner of the collection, `None` if the API returns no or an empty owner.
+    #[serde(default, deserialize_with = "option_address_from_str")]
+    pub owner: Option<Address>,
     pub safelist_status: SafelistStatus,
     pub category: String,
     pub is_disabled: bool,
@@ -415,7 +1194,8 @@ pub struct CollectionResponse {
     pub twitter_username: Option<String>,
     pub instagram_username: Option<String>,
     pub contracts: Vec<Contract>,
-    pub editors: Vec<String>,
+    #[serde(default, deserialize_with = "addresses_from_str")]
+    pub editors: Vec<Address>,
     pub fees: Vec<CollectionFee>,
     pub required_zone: Option<String>,
     pub rarity: Option<CollectionRarity>,
@@ -424,13 +1204,260 @@ pub struct CollectionResponse {
     pub created_date: NaiveDate,
 }
 
+/// Sort order of the collections list endpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CollectionOrderBy {
+    CreatedDate,
+    OneDayChange,
+    SevenDayVolume,
+    SevenDayChange,
+    NumOwners,
+    MarketCap,
+}
+
+/// Period over which trending collections are ranked by the change of their floor price.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrendingPeriod {
+    OneDay,
+    SevenDays,
+    /// Not supported by the collections list endpoint, which has no 30 day ordering.
+    ThirtyDays,
+}
+
+impl TrendingPeriod {
+    /// The order of the collections list endpoint ranking collections by their change over the period. `None` if
+    /// the endpoint does not support the period.
+    pub fn order_by(&self) -> Option<CollectionOrderBy> {
+        match self {
+            TrendingPeriod::OneDay => Some(CollectionOrderBy::OneDayChange),
+            TrendingPeriod::SevenDays => Some(CollectionOrderBy::SevenDayChange),
+            TrendingPeriod::ThirtyDays => None,
+        }
+    }
+}
+
+/// Query parameters for the collections list endpoint.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct ListCollectionsRequest {
+    /// Only collections with a contract on this chain.
+    pub chain: Option<Chain>,
+    /// Only collections created by the account with this OpenSea username.
+    pub creator_username: Option<String>,
+    /// Include collections hidden by OpenSea, e.g. spam.
+    pub include_hidden: Option<bool>,
+    /// Number of collections to retrieve (1 - 100)
+    pub limit: Option<u8>,
+    /// The cursor for the next page of results
+    pub next: Option<Cursor>,
+    pub order_by: Option<CollectionOrderBy>,
+    /// Additional query parameters not modeled by this request, appended to the query string verbatim.
+    #[serde(skip)]
+    pub extra_params: Vec<(String, String)>,
+}
+
+impl QueryRequest for ListCollectionsRequest {
+    fn extra_params(&self) -> &[(String, String)] {
+        &self.extra_params
+    }
+}
+
+/// Response of the collections list endpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct ListCollectionsResponse {
+    pub collections: Vec<CollectionSummary>,
+    /// Cursor for the next page. `None` if there are no more collections to retrieve.
+    #[serde(default)]
+    pub next: Option<Cursor>,
+}
+
+/// A collection as returned by the collections list endpoint, without fees, rarity and stats. Use
+/// [`crate::OpenSeaV2Client::get_collection`] for the full details.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct CollectionSummary {
+    pub collection: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub image_url: Option<String>,
+    pub banner_image_url: Option<String>,
+    /// The owner of the collection, `None` if the API returns no or an empty owner.
+    #[serde(default, deserialize_with = "option_address_from_str")]
+    pub owner: Option<Address>,
+    pub safelist_status: SafelistStatus,
+    pub category: String,
+    pub is_disabled: bool,
+    pub is_nsfw: bool,
+    pub trait_offers_enabled: bool,
+    pub collection_offers_enabled: bool,
+    pub opensea_url: String,
+    pub project_url: Option<String>,
+    pub wiki_url: Option<String>,
+    pub discord_url: Option<String>,
+    pub telegram_url: Option<String>,
+    pub twitter_username: Option<String>,
+    pub instagram_username: Option<String>,
+    pub contracts: Vec<Contract>,
+}
+
+impl CollectionResponse {
+    /// Returns an error if the collection does not accept collection offers. Check this before building a
+    /// collection offer to avoid a rejection by OpenSea.
+    pub fn ensure_collection_offers_enabled(&self) -> Result<(), OpenSeaApiError> {
+        if !self.collection_offers_enabled {
+            return Err(OpenSeaApiError::Other(format!("Collection offers are disabled for collection {}", self.collection)));
+        }
+        Ok(())
+    }
+
+    /// Returns an error if the collection does not accept trait offers. Check this before building a trait offer
+    /// to avoid a rejection by OpenSea.
+    pub fn ensure_trait_offers_enabled(&self) -> Result<(), OpenSeaApiError> {
+        if !self.trait_offers_enabled {
+            return Err(OpenSeaApiError::Other(format!("Trait offers are disabled for collection {}", self.collection)));
+        }
+        Ok(())
+    }
+
+    /// Sum of all fees of the collection in basis points, e.g. `750` for a 2.5% OpenSea and a 5% creator fee.
+    pub fn total_fee_basis_points(&self) -> u64 {
+        self.fees.iter().map(CollectionFee::basis_points).sum()
+    }
+
+    /// The fees paid to the creators of the collection, i.e. all fees not going to OpenSea.
+    pub fn creator_fees(&self) -> Vec<&CollectionFee> {
+        self.fees.iter().filter(|fee| fee.recipient != OPENSEA_FEE_RECIPIENT).collect()
+    }
+
+    /// The OpenSea marketplace fee, `None` if the collection does not list one.
+    pub fn opensea_fee(&self) -> Option<&CollectionFee> {
+        self.fees.iter().find(|fee| fee.recipient == OPENSEA_FEE_RECIPIENT)
+    }
+
+    /// The payment token with the given symbol, compared case-insensitively. `None` if the collection has no such
+    /// token or no payment tokens at all.
+    pub fn payment_token_for(&self, symbol: &str) -> Option<&PaymentToken> {
+        self.payment_tokens.iter().flatten().find(|token| token.symbol.eq_ignore_ascii_case(symbol))
+    }
+
+    /// The native currency of the chain, i.e. the payment token with the zero address. `None` if the collection
+    /// does not accept it or has no payment tokens.
+    pub fn native_token(&self) -> Option<&PaymentToken> {
+        self.payment_tokens.iter().flatten().find(|token| Address::from_str(&token.address).is_ok_and(|address| address.is_zero()))
+    }
+}
+
+/// Response from the OpenSea collection stats endpoint.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct CollectionStatsResponse {
+    pub total: StatsTotal,
+    pub intervals: Vec<StatsInterval>,
+}
+
+impl CollectionStatsResponse {
+    /// Returns the stats of an interval by its name e.g. `one_day`, `seven_day` or `thirty_day`.
+    pub fn interval(&self, name: &str) -> Option<&StatsInterval> {
+        self.intervals.iter().find(|interval| interval.interval == name)
+    }
+
+    /// The currency of the floor price taken from `floor_price_symbol`, ETH if not set.
+    pub fn floor_price_currency(&self) -> Currency {
+        match self.total.floor_price_symbol.as_deref() {
+            None => Currency::Eth,
+            Some(symbol) if symbol.eq_ignore_ascii_case("ETH") => Currency::Eth,
+            Some(symbol) => Currency::Other(symbol.to_string()),
+        }
+    }
+
+    /// The floor price in base units of its currency with `decimals` decimals, e.g. 18 for ETH or the decimals of the
+    /// payment token of the collection with the `floor_price_symbol`. `None` if the collection has no floor or the
+    /// floor has more digits than `decimals`.
+    pub fn floor_price(&self, decimals: u16) -> Option<Price> {
+        let floor_price = self.total.floor_price.as_ref()?;
+        Price::from_decimal(floor_price.as_str(), decimals, self.floor_price_currency()).ok()
+    }
+}
+
+/// All-time stats of a collection.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct StatsTotal {
+    pub volume: f64,
+    pub sales: u64,
+    pub average_price: f64,
+    pub num_owners: u64,
+    pub market_cap: f64,
+    /// The floor price as returned by the API, kept exact to convert it into base units. See
+    /// [`CollectionStatsResponse::floor_price`].
+    pub floor_price: Option<Number>,
+    pub floor_price_symbol: Option<String>,
+}
+
+/// Stats of a collection for a time interval e.g. one_day.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct StatsInterval {
+    pub interval: String,
+    pub volume: f64,
+    pub volume_diff: f64,
+    pub volume_change: f64,
+    pub sales: u64,
+    pub sales_diff: i64,
+    pub average_price: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Contract {
     pub address: Address,
     pub chain: Chain,
 }
 
+/// Response from the contract endpoint.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct ContractResponse {
+    pub address: Address,
+    pub chain: Chain,
+    /// The slug of the collection the contract belongs to, if any. Use it with `get_collection`.
+    pub collection: Option<String>,
+    /// The token standard e.g. erc721 or erc1155.
+    pub contract_standard: String,
+    pub name: String,
+    #[serde(default, alias = "total_supply")]
+    pub supply: Option<u64>,
+}
+
+/// Response from the account endpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct AccountResponse {
+    pub address: Address,
+    /// The OpenSea username, `None` if the account has not set one.
+    pub username: Option<String>,
+    pub profile_image_url: Option<String>,
+    pub banner_image_url: Option<String>,
+    pub website: Option<String>,
+    #[serde(default)]
+    pub social_media_accounts: Vec<SocialMediaAccount>,
+    pub bio: Option<String>,
+    pub joined_date: Option<String>,
+}
+
+/// A social media account linked to an OpenSea account.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct SocialMediaAccount {
+    pub platform: String,
+    pub username: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Collection {
     pub banner_image_url: Option<String>,
     pub chat_url: Option<String>,
@@ -469,12 +1496,14 @@ pub struct Collection {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct CollectionFees {
     pub seller_fees: HashMap<String, u64>,
     pub opensea_fees: HashMap<String, u64>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Asset {
     pub id: u64,
     pub token_id: String,
@@ -495,13 +1524,42 @@ pub struct Asset {
     pub decimals: Option<u64>,
     pub token_metadata: Option<String>,
     pub is_nsfw: bool,
-    //XXX not documented
-    pub owner: Value,
+    /// Owners of the asset. A single owner object is returned as a one-element vec, ERC1155 assets can have many.
+    #[serde(default, deserialize_with = "ownerships_from_value")]
+    pub owner: Vec<Ownership>,
+}
+
+/// Ownership of an asset, including the quantity held for ERC1155 assets.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Ownership {
+    pub owner: Account,
+    pub quantity: String,
+}
+
+/// Helper function to deserialize owners given as null, a single account, a single ownership or a list of ownerships.
+pub(crate) fn ownerships_from_value<'de, D>(deserializer: D) -> Result<Vec<Ownership>, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let val = Value::deserialize(deserializer)?;
+    match val {
+        Value::Null => Ok(vec![]),
+        Value::Array(_) => serde_json::from_value(val).map_err(de::Error::custom),
+        Value::Object(ref map) if map.contains_key("owner") => Ok(vec![serde_json::from_value(val).map_err(de::Error::custom)?]),
+        Value::Object(_) => {
+            let owner = serde_json::from_value(val).map_err(de::Error::custom)?;
+            Ok(vec![Ownership { owner, quantity: "1".to_string() }])
+        }
+        _ => Err(de::Error::custom(format!("Invalid owner: {val:?}"))),
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct AssetContract {
-    pub address: String,
+    #[serde(deserialize_with = "address_from_str")]
+    pub address: Address,
     pub asset_contract_type: String,
     pub chain_identifier: String,
     pub created_date: String,
@@ -527,6 +1585,7 @@ pub struct AssetContract {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
 pub struct Bundle {
     pub assets: Vec<Asset>,
     pub maker: Value,
@@ -556,6 +1615,108 @@ pub(crate) mod tests {
           }"#;
         let account: Account = serde_json::from_str(account).unwrap();
         assert_eq!(account.user, Some(UserId("14210173".to_string())));
+        assert_eq!(account.address, Address::from_str("0x193d3Eda0Dbabd55453dE814eF08a6255446c911").unwrap());
+    }
+
+    #[test]
+    fn can_deserialize_account_address_checksummed_and_lowercase() {
+        let expected = Address::from_str("0x193d3Eda0Dbabd55453dE814eF08a6255446c911").unwrap();
+        for address in ["0x193d3Eda0Dbabd55453dE814eF08a6255446c911", "0x193d3eda0dbabd55453de814ef08a6255446c911"] {
+            let account = json!({ "user": null, "profile_img_url": "", "address": address, "config": "" });
+            let account: Account = serde_json::from_value(account).unwrap();
+            assert_eq!(account.address, expected);
+        }
+
+        let account = json!({ "user": null, "profile_img_url": "", "address": "not an address", "config": "" });
+        assert!(serde_json::from_value::<Account>(account).is_err());
+    }
+
+    #[test]
+    fn can_deserialize_collection_fee_recipient_checksummed_and_lowercase() {
+        let expected = Address::from_str("0x0000a26b00c1F0DF003000390027140000fAa719").unwrap();
+        for recipient in ["0x0000a26b00c1F0DF003000390027140000fAa719", "0x0000a26b00c1f0df003000390027140000faa719"] {
+            let fee = json!({ "fee": 2.5, "recipient": recipient, "required": true });
+            let fee: CollectionFee = serde_json::from_value(fee).unwrap();
+            assert_eq!(fee.recipient, expected);
+        }
+    }
+
+    #[test]
+    fn can_deserialize_asset_contract_address_checksummed_and_lowercase() {
+        let expected = Address::from_str("0xBC4CA0EdA7647A8aB7C2061c2E118A18a936f13D").unwrap();
+        for address in ["0xBC4CA0EdA7647A8aB7C2061c2E118A18a936f13D", "0xbc4ca0eda7647a8ab7c2061c2e118a18a936f13d"] {
+            let contract = json!({
+                "address": address,
+                "asset_contract_type": "non-fungible",
+                "chain_identifier": "ethereum",
+                "created_date": "2021-04-22T23:14:03.967940",
+                "name": "BoredApeYachtClub",
+                "nft_version": null,
+                "opensea_version": null,
+                "owner": 5372214,
+                "schema_name": "ERC721",
+                "symbol": "BAYC",
+                "total_supply": null,
+                "description": null,
+                "external_link": null,
+                "image_url": null,
+                "default_to_fiat": false,
+                "dev_buyer_fee_basis_points": 0,
+                "dev_seller_fee_basis_points": 250,
+                "only_proxied_transfers": false,
+                "opensea_buyer_fee_basis_points": 0,
+                "opensea_seller_fee_basis_points": 250,
+                "buyer_fee_basis_points": 0,
+                "seller_fee_basis_points": 500,
+                "payout_address": null
+            });
+            let contract: AssetContract = serde_json::from_value(contract).unwrap();
+            assert_eq!(contract.address, expected);
+        }
+    }
+
+    fn asset_with_owner(owner: Value) -> Asset {
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("resources/response_get_listings.json");
+        let res = std::fs::read_to_string(d).unwrap();
+        let res: Value = serde_json::from_str(&res).unwrap();
+        let mut asset = res["orders"][0]["maker_asset_bundle"]["assets"][0].clone();
+        asset["owner"] = owner;
+        serde_json::from_value(asset).unwrap()
+    }
+
+    fn account_json(address: &str) -> Value {
+        json!({ "user": null, "profile_img_url": "", "address": address, "config": "" })
+    }
+
+    #[test]
+    fn can_deserialize_asset_without_owner() {
+        assert!(asset_with_owner(Value::Null).owner.is_empty());
+    }
+
+    #[test]
+    fn can_deserialize_asset_with_single_owner() {
+        let address = "0x193d3eda0dbabd55453de814ef08a6255446c911";
+        let asset = asset_with_owner(account_json(address));
+        assert_eq!(asset.owner.len(), 1);
+        assert_eq!(asset.owner[0].owner.address, Address::from_str(address).unwrap());
+        assert_eq!(asset.owner[0].quantity, "1");
+
+        let asset = asset_with_owner(json!({ "owner": account_json(address), "quantity": "3" }));
+        assert_eq!(asset.owner.len(), 1);
+        assert_eq!(asset.owner[0].quantity, "3");
+    }
+
+    #[test]
+    fn can_deserialize_asset_with_multiple_owners() {
+        let asset = asset_with_owner(json!([
+            { "owner": account_json("0x193d3eda0dbabd55453de814ef08a6255446c911"), "quantity": "5" },
+            { "owner": account_json("0x909f0506a372a8aeed6a812d4a04139d5a1a81ea"), "quantity": "2" }
+        ]));
+        assert_eq!(asset.owner.len(), 2);
+        assert_eq!(asset.owner[0].quantity, "5");
+        assert_eq!(asset.owner[1].owner.address, Address::from_str("0x909f0506a372a8aeed6a812d4a04139d5a1a81ea").unwrap());
+        assert_eq!(asset.owner[1].quantity, "2");
     }
 
     #[test]
@@ -565,7 +1726,142 @@ pub(crate) mod tests {
         println!("{}", d.display());
         let res = std::fs::read_to_string(d).unwrap();
         let res: RetrieveListingsResponse = serde_json::from_str(&res).unwrap();
-        assert_eq!(res.next, Some("LXBrPTExNTE5Njk3NjYw".to_string()));
+        assert_eq!(res.next, Some(Cursor::from("LXBrPTExNTE5Njk3NjYw")));
+    }
+
+    #[test]
+    fn can_deserialize_offers_response() {
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("resources/response_get_offers.json");
+        let res = std::fs::read_to_string(d).unwrap();
+        let res: RetrieveOffersResponse = serde_json::from_str(&res).unwrap();
+        assert_eq!(res.next, None);
+        assert_eq!(res.orders.len(), 1);
+
+        let offer = &res.orders[0];
+        assert_eq!(offer.order_hash.as_deref(), Some("0x6e3c5d1f5a3e4b2f1b0b5e9c3a1d7f4e8c2b6a9d0e1f2a3b4c5d6e7f8a9b0c1d"));
+        assert_eq!(offer.side, orders::OrderSide::Bid);
+        assert_eq!(offer.current_price_u256(), Some(U256::from(10000000000000000u64)));
+        assert_eq!(offer.payment_item_type(), Some(orders::ItemType::ERC20));
+        assert_eq!(offer.payment_token_address().as_deref(), Some("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"));
+    }
+
+    #[test]
+    fn can_create_fulfill_offer_request_from_order() {
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("resources/response_get_offers.json");
+        let res = std::fs::read_to_string(d).unwrap();
+        let mut offer = serde_json::from_str::<RetrieveOffersResponse>(&res).unwrap().orders.remove(0);
+        let fulfiller = Address::from_str("0x67d58520775af7848f3ee2adaa227435f5a91a04").unwrap();
+        let consideration = ConsiderationInput {
+            asset_contract_address: Address::from_str("0xA604060890923Ff400e8c6f5290461A83AEDACec").unwrap(),
+            token_id: "42".to_string(),
+        };
+
+        let req = FulfillOfferRequest::from_order(&offer, Chain::Ethereum, fulfiller, consideration.clone()).unwrap();
+        assert_eq!(req.offer.hash, B256::from_str(offer.order_hash.as_deref().unwrap()).unwrap());
+        assert_eq!(req.offer.protocol_version, ProtocolVersion::V1_5);
+        let json = serde_json::to_value(&req).unwrap();
+        assert_eq!(json["offer"]["protocol_address"], SEAPORT_V5);
+        assert_eq!(json["offer"]["chain"], "ethereum");
+        assert_eq!(json["fulfiller"]["address"], "0x67d58520775af7848f3ee2adaa227435f5a91a04");
+        assert_eq!(json["consideration"]["token_id"], "42");
+
+        let other_contract = ConsiderationInput { asset_contract_address: fulfiller, token_id: "42".to_string() };
+        assert!(matches!(
+            FulfillOfferRequest::from_order(&offer, Chain::Ethereum, fulfiller, other_contract),
+            Err(OpenSeaApiError::Other(_))
+        ));
+
+        offer.side = orders::OrderSide::Ask;
+        assert!(matches!(
+            FulfillOfferRequest::from_order(&offer, Chain::Ethereum, fulfiller, consideration),
+            Err(OpenSeaApiError::Other(_))
+        ));
+    }
+
+    fn listings_with_prices(prices: &[&str]) -> RetrieveListingsResponse {
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("resources/response_get_listings.json");
+        let res = std::fs::read_to_string(d).unwrap();
+        let mut res: RetrieveListingsResponse = serde_json::from_str(&res).unwrap();
+        let order = res.orders.remove(0);
+        res.orders = prices
+            .iter()
+            .map(|price| {
+                let mut order = order.clone();
+                order.current_price = price.to_string();
+                order.order_hash = Some(format!("hash-{price}"));
+                order
+            })
+            .collect();
+        res
+    }
+
+    #[test]
+    fn can_find_cheapest_listing() {
+        let res = listings_with_prices(&["3000", "not a number", "1000", "2000"]);
+        assert_eq!(res.cheapest().unwrap().current_price, "1000");
+
+        let res = listings_with_prices(&["not a number"]);
+        assert!(res.cheapest().is_none());
+
+        let res = listings_with_prices(&[]);
+        assert!(res.cheapest().is_none());
+    }
+
+    #[test]
+    fn can_filter_listings_by_price_range() {
+        let res = listings_with_prices(&["1000", "2000", "not a number", "3000", "4000"]);
+        let prices = |orders: Vec<Order>| orders.into_iter().map(|order| order.current_price).collect::<Vec<_>>();
+
+        assert_eq!(prices(res.filter_price_range(Some(U256::from(2000)), Some(U256::from(3000)))), vec!["2000", "3000"]);
+        assert_eq!(prices(res.filter_price_range(None, Some(U256::from(1000)))), vec!["1000"]);
+        assert_eq!(prices(res.filter_price_range(Some(U256::from(3500)), None)), vec!["4000"]);
+        assert_eq!(prices(res.filter_price_range(None, None)), vec!["1000", "2000", "3000", "4000"]);
+        assert!(res.filter_price_range(Some(U256::from(5000)), None).is_empty());
+    }
+
+    #[test]
+    fn can_sort_listings_by_price() {
+        let mut res = listings_with_prices(&["3000", "not a number", "1000", "20000000000000000000000", "2000"]);
+        res.sort_by_price();
+        let prices: Vec<_> = res.orders.iter().map(|order| order.current_price.as_str()).collect();
+        assert_eq!(prices, vec!["1000", "2000", "3000", "20000000000000000000000", "not a number"]);
+    }
+
+    #[test]
+    fn can_deserialize_get_contract_response() {
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("resources/response_get_contract.json");
+        let res = std::fs::read_to_string(d).unwrap();
+        let res: ContractResponse = serde_json::from_str(&res).unwrap();
+        assert_eq!(res.address, Address::from_str("0xBC4CA0EdA7647A8aB7C2061c2E118A18a936f13D").unwrap());
+        assert_eq!(res.chain, Chain::Ethereum);
+        assert_eq!(res.collection, Some("boredapeyachtclub".to_string()));
+        assert_eq!(res.contract_standard, "erc721");
+        assert_eq!(res.supply, Some(10000));
+    }
+
+    fn contract_response_with_extra_field() -> String {
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("resources/response_get_contract.json");
+        let mut res: Value = serde_json::from_str(&std::fs::read_to_string(d).unwrap()).unwrap();
+        res["new_field"] = json!("value");
+        res.to_string()
+    }
+
+    #[cfg(not(feature = "strict-schema"))]
+    #[test]
+    fn ignores_unknown_response_fields() {
+        assert!(serde_json::from_str::<ContractResponse>(&contract_response_with_extra_field()).is_ok());
+    }
+
+    #[cfg(feature = "strict-schema")]
+    #[test]
+    fn rejects_unknown_response_fields_in_strict_mode() {
+        let err = serde_json::from_str::<ContractResponse>(&contract_response_with_extra_field()).unwrap_err();
+        assert!(err.to_string().contains("unknown field `new_field`"));
     }
 
     #[test]
@@ -579,6 +1875,298 @@ pub(crate) mod tests {
         assert_eq!(res.created_date, NaiveDate::from_ymd_opt(2024, 2, 20).unwrap());
     }
 
+    #[test]
+    fn can_deserialize_get_collection_stats_response() {
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("resources/response_get_collection_stats.json");
+        let res = std::fs::read_to_string(d).unwrap();
+        let res: CollectionStatsResponse = serde_json::from_str(&res).unwrap();
+        assert_eq!(res.total.sales, 48730);
+        assert_eq!(res.total.floor_price.as_ref().map(Number::as_str), Some("11.3"));
+        assert_eq!(res.total.floor_price_symbol, Some("ETH".to_string()));
+        assert_eq!(res.intervals.len(), 3);
+    }
+
+    #[test]
+    fn can_get_collection_stats_interval_by_name() {
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("resources/response_get_collection_stats.json");
+        let res = std::fs::read_to_string(d).unwrap();
+        let res: CollectionStatsResponse = serde_json::from_str(&res).unwrap();
+
+        let one_day = res.interval("one_day").unwrap();
+        assert_eq!(one_day.sales, 9);
+        assert!((one_day.volume - 101.64984).abs() < 1e-9);
+        assert!((one_day.average_price - 11.294426666666666).abs() < 1e-9);
+
+        let seven_day = res.interval("seven_day").unwrap();
+        assert_eq!(seven_day.sales, 46);
+        assert!((seven_day.volume_diff + 183.55874).abs() < 1e-9);
+
+        let thirty_day = res.interval("thirty_day").unwrap();
+        assert_eq!(thirty_day.sales, 269);
+        assert_eq!(thirty_day.sales_diff, -82);
+
+        assert!(res.interval("one_year").is_none());
+    }
+
+    #[test]
+    fn can_check_offers_enabled_for_collection() {
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("resources/response_get_collection.json");
+        let res = std::fs::read_to_string(d).unwrap();
+        let mut res: CollectionResponse = serde_json::from_str(&res).unwrap();
+
+        assert!(res.ensure_collection_offers_enabled().is_ok());
+        assert!(matches!(res.ensure_trait_offers_enabled(), Err(OpenSeaApiError::Other(_))));
+
+        res.trait_offers_enabled = true;
+        res.collection_offers_enabled = false;
+        assert!(res.ensure_trait_offers_enabled().is_ok());
+        assert!(matches!(res.ensure_collection_offers_enabled(), Err(OpenSeaApiError::Other(_))));
+    }
+
+    #[test]
+    fn can_deserialize_payment_token_chain() {
+        let token = json!({
+            "symbol": "WETH",
+            "address": "0x7ceb23fd6bc0add59e62ac25578270cff1b9f619",
+            "chain": "matic",
+            "image": null,
+            "name": "Wrapped Ether",
+            "decimals": 18,
+            "eth_price": "1.000000000000000",
+            "usd_price": "2307.830000000000000000"
+        });
+        let payment_token: PaymentToken = serde_json::from_value(token.clone()).unwrap();
+        assert_eq!(payment_token.parsed_chain(), Some(Chain::Polygon));
+        assert_eq!(serde_json::to_value(&payment_token).unwrap(), token);
+
+        let mut token = token;
+        token["chain"] = "mainnet".into();
+        assert_eq!(serde_json::from_value::<PaymentToken>(token.clone()).unwrap().parsed_chain(), Some(Chain::Ethereum));
+
+        token["chain"] = "blast".into();
+        let payment_token: PaymentToken = serde_json::from_value(token).unwrap();
+        assert_eq!(payment_token.chain, "blast");
+        assert_eq!(payment_token.parsed_chain(), None);
+    }
+
+    #[test]
+    fn can_get_payment_tokens_of_collection() {
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("resources/response_get_collection.json");
+        let mut res: Value = serde_json::from_str(&std::fs::read_to_string(d).unwrap()).unwrap();
+
+        let collection: CollectionResponse = serde_json::from_value(res.clone()).unwrap();
+        assert!(collection.payment_tokens.is_none());
+        assert!(collection.payment_token_for("ETH").is_none());
+        assert!(collection.native_token().is_none());
+
+        let token = |symbol: &str, address: &str| {
+            json!({
+                "symbol": symbol,
+                "address": address,
+                "chain": "ethereum",
+                "image": null,
+                "name": null,
+                "decimals": 18,
+                "eth_price": "1.000000000000000",
+                "usd_price": "2307.830000000000000000"
+            })
+        };
+        res["payment_tokens"] = json!([
+            token("WETH", "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"),
+            token("ETH", "0x0000000000000000000000000000000000000000")
+        ]);
+        let collection: CollectionResponse = serde_json::from_value(res).unwrap();
+        assert_eq!(collection.payment_token_for("weth").unwrap().address, "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+        assert!(collection.payment_token_for("USDC").is_none());
+        assert_eq!(collection.native_token().unwrap().symbol, "ETH");
+    }
+
+    #[test]
+    fn can_validate_seaport_signature() {
+        let r = "a".repeat(64);
+        let s = "b".repeat(64);
+        assert!(validate_seaport_signature(&format!("0x{r}{s}1b")).is_ok());
+        assert!(validate_seaport_signature(&format!("0x{r}{s}1c")).is_ok());
+        assert!(validate_seaport_signature(&format!("{r}{s}1c")).is_ok());
+        assert!(validate_seaport_signature(&format!("0x{r}{s}")).is_ok());
+
+        // Bulk order signatures append a 3 byte order index and the proof hashes
+        let proof = "cd".repeat(32 * 3);
+        assert!(validate_seaport_signature(&format!("0x{r}{s}1b000005{proof}")).is_ok());
+        assert!(validate_seaport_signature(&format!("0x{r}{s}000005{proof}")).is_ok());
+        let err = validate_seaport_signature(&format!("0x{r}{s}05000005{proof}")).unwrap_err();
+        assert!(err.to_string().contains("recovery id 5"), "{err}");
+        assert!(validate_seaport_signature(&format!("0x{r}{s}1b000005")).is_err());
+        assert!(validate_seaport_signature(&format!("0x{r}{s}1b000005{}", "cd".repeat(32 * 25))).is_err());
+
+        let err = validate_seaport_signature(&format!("0x{r}{s}05")).unwrap_err();
+        assert!(err.to_string().contains("recovery id 5"), "{err}");
+        let err = validate_seaport_signature(&format!("0x{r}")).unwrap_err();
+        assert!(err.to_string().contains("has 32 bytes"), "{err}");
+        assert!(validate_seaport_signature("0x").is_err());
+        assert!(validate_seaport_signature("0xzz").is_err());
+    }
+
+    #[test]
+    fn can_build_fulfill_listing_request() {
+        let hash = B256::from_str("0x541a9eb3962494caffeda36a495cc978c7ecc21c6b714aaabc678187d3da9ac7").unwrap();
+        let fulfiller = Address::from_str("0x67d58520775af7848f3ee2adaa227435f5a91a04").unwrap();
+        let req = FulfillListingRequest::builder()
+            .order_hash(hash)
+            .chain(Chain::Base)
+            .protocol_version(ProtocolVersion::V1_6)
+            .fulfiller(fulfiller)
+            .build()
+            .unwrap();
+        assert_eq!(req.listing.hash, hash);
+        assert_eq!(req.listing.chain, Chain::Base);
+        assert_eq!(req.fulfiller.address, fulfiller);
+        let json = serde_json::to_value(&req).unwrap();
+        assert_eq!(json["listing"]["protocol_address"], SEAPORT_V6);
+    }
+
+    #[test]
+    fn fulfill_listing_request_builder_requires_all_fields() {
+        let builder = FulfillListingRequest::builder().order_hash(B256::ZERO).chain(Chain::Ethereum).fulfiller(Address::ZERO);
+        let err = builder.clone().build().unwrap_err();
+        assert!(matches!(err, OpenSeaApiError::Other(ref msg) if msg.contains("protocol_version")), "{err}");
+        assert!(builder.protocol_version(ProtocolVersion::V1_5).build().is_ok());
+
+        let err = FulfillListingRequest::builder().build().unwrap_err();
+        assert!(err.to_string().contains("order_hash"), "{err}");
+    }
+
+    #[test]
+    fn can_check_safelist_status() {
+        let statuses = [
+            (SafelistStatus::NotRequested, false, false),
+            (SafelistStatus::Requested, false, false),
+            (SafelistStatus::Approved, false, true),
+            (SafelistStatus::Verified, true, true),
+            (SafelistStatus::DisabledTopTrending, false, false),
+        ];
+        for (status, verified, trusted) in statuses {
+            assert_eq!(status.is_verified(), verified, "{status:?}");
+            assert_eq!(status.is_trusted(), trusted, "{status:?}");
+        }
+    }
+
+    #[test]
+    fn can_deserialize_list_collections_response() {
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("resources/response_get_collections.json");
+        let res = std::fs::read_to_string(d).unwrap();
+        let res: ListCollectionsResponse = serde_json::from_str(&res).unwrap();
+        assert_eq!(res.collections.len(), 2);
+        assert_eq!(res.collections[0].collection, "sheboshis");
+        assert_eq!(res.collections[0].owner, Some(Address::from_str("0xc7d0445ac2947760b3dd388b8586adf079972bf3").unwrap()));
+        assert!(res.collections[1].safelist_status.is_verified());
+        assert_eq!(res.collections[1].contracts[0].chain, Chain::Ethereum);
+        assert_eq!(res.next, Some(Cursor::from("LWNvbGxlY3Rpb25fc2x1Zz1zaGlib3NoaXM=")));
+    }
+
+    #[test]
+    fn can_serialize_list_collections_request() {
+        let req = ListCollectionsRequest {
+            chain: Some(Chain::Base),
+            creator_username: Some("cakevm".to_string()),
+            limit: Some(20),
+            order_by: Some(CollectionOrderBy::SevenDayVolume),
+            ..Default::default()
+        };
+        assert_eq!(
+            to_query_pairs(&req).unwrap(),
+            vec![
+                ("chain".to_string(), "base".to_string()),
+                ("creator_username".to_string(), "cakevm".to_string()),
+                ("limit".to_string(), "20".to_string()),
+                ("order_by".to_string(), "seven_day_volume".to_string()),
+            ]
+        );
+        assert!(to_query_pairs(&ListCollectionsRequest::default()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn cursor_serializes_as_raw_string() {
+        let cursor = Cursor::from("LXBrPTExNTE5Njk3NjYw");
+        assert_eq!(serde_json::to_value(&cursor).unwrap(), json!("LXBrPTExNTE5Njk3NjYw"));
+        assert_eq!(serde_json::from_value::<Cursor>(json!("LXBrPTExNTE5Njk3NjYw")).unwrap(), cursor);
+        assert_eq!(cursor.to_string(), "LXBrPTExNTE5Njk3NjYw");
+
+        let req = RetrieveOffersRequest::builder().cursor(cursor.clone()).build().unwrap();
+        assert_eq!(to_query_pairs(&req).unwrap(), vec![("cursor".to_string(), "LXBrPTExNTE5Njk3NjYw".to_string())]);
+        let req = GetAllListingsRequest { limit: None, next: Some(cursor), ..Default::default() };
+        assert_eq!(to_query_pairs(&req).unwrap(), vec![("next".to_string(), "LXBrPTExNTE5Njk3NjYw".to_string())]);
+    }
+
+    #[test]
+    fn can_deserialize_null_orders_and_listings() {
+        let res: RetrieveListingsResponse = serde_json::from_value(json!({"next": null, "previous": null, "orders": null})).unwrap();
+        assert!(res.orders.is_empty());
+        let res: RetrieveOffersResponse = serde_json::from_value(json!({"next": null, "previous": null, "orders": null})).unwrap();
+        assert!(res.orders.is_empty());
+        let res: GetAllListingsResponse = serde_json::from_value(json!({"listings": null, "next": "abc"})).unwrap();
+        assert!(res.listings.is_empty());
+        assert_eq!(res.next.as_ref().map(Cursor::as_str), Some("abc"));
+    }
+
+    #[test]
+    fn can_deserialize_collection_owner_and_editors() {
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("resources/response_get_collection.json");
+        let mut res: Value = serde_json::from_str(&std::fs::read_to_string(d).unwrap()).unwrap();
+        let owner = Address::from_str("0xc7d0445ac2947760b3dd388b8586adf079972bf3").unwrap();
+        let editor = Address::from_str("0x909f0506a372a8aeed6a812d4a04139d5a1a81ea").unwrap();
+
+        let collection: CollectionResponse = serde_json::from_value(res.clone()).unwrap();
+        assert_eq!(collection.owner, Some(owner));
+        assert_eq!(collection.editors, vec![owner]);
+
+        for missing_owner in [json!(""), Value::Null] {
+            let mut res = res.clone();
+            res["owner"] = missing_owner;
+            assert_eq!(serde_json::from_value::<CollectionResponse>(res).unwrap().owner, None);
+        }
+        let mut without_owner = res.clone();
+        without_owner.as_object_mut().unwrap().remove("owner");
+        assert_eq!(serde_json::from_value::<CollectionResponse>(without_owner).unwrap().owner, None);
+
+        res["editors"] = json!(["0xC7D0445AC2947760B3DD388B8586ADF079972BF3", " 0x909f0506a372a8aeed6a812d4a04139d5a1a81ea "]);
+        let collection: CollectionResponse = serde_json::from_value(res.clone()).unwrap();
+        assert_eq!(collection.editors, vec![owner, editor]);
+
+        res["editors"] = Value::Null;
+        assert!(serde_json::from_value::<CollectionResponse>(res.clone()).unwrap().editors.is_empty());
+
+        res["editors"] = json!(["not an address"]);
+        assert!(serde_json::from_value::<CollectionResponse>(res).is_err());
+    }
+
+    #[test]
+    fn can_split_creator_and_opensea_fees() {
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("resources/response_get_collection.json");
+        let res = std::fs::read_to_string(d).unwrap();
+        let mut res: CollectionResponse = serde_json::from_str(&res).unwrap();
+
+        assert_eq!(res.total_fee_basis_points(), 750);
+        let opensea_fee = res.opensea_fee().unwrap();
+        assert_eq!(opensea_fee.basis_points(), 250);
+        assert_eq!(opensea_fee.recipient, OPENSEA_FEE_RECIPIENT);
+        let creator_fees = res.creator_fees();
+        assert_eq!(creator_fees.len(), 1);
+        assert_eq!(creator_fees[0].basis_points(), 500);
+        assert_eq!(creator_fees[0].recipient, Address::from_str("0xc7d0445ac2947760b3dd388b8586adf079972bf3").unwrap());
+
+        res.fees.retain(|fee| fee.recipient != OPENSEA_FEE_RECIPIENT);
+        assert!(res.opensea_fee().is_none());
+        assert_eq!(res.total_fee_basis_points(), 500);
+    }
+
     #[test]
     #[ignore = "Inconsistency between mainnet and testnet structures"]
     fn can_deserialize_test_response() {
@@ -587,7 +2175,7 @@ pub(crate) mod tests {
         println!("{}", d.display());
         let res = std::fs::read_to_string(d).unwrap();
         let res: RetrieveListingsResponse = serde_json::from_str(&res).unwrap();
-        assert_eq!(res.next, Some("LXBrPTEyNDkyNTQ=".to_string()));
+        assert_eq!(res.next, Some(Cursor::from("LXBrPTEyNDkyNTQ=")));
     }
 
     #[test]
@@ -610,6 +2198,242 @@ pub(crate) mod tests {
         );
     }
 
+    #[test]
+    fn can_convert_retrieve_listing_request_with_protocol_address_to_qs() {
+        let req = RetrieveListingsRequest { protocol_address: Address::from_str(SEAPORT_V6).ok(), ..Default::default() };
+        assert_eq!(
+            req.to_qs_vec().unwrap(),
+            vec![("protocol_address".to_string(), "0x0000000000000068f116a894984e2db1123eb395".to_string())]
+        );
+
+        let req = RetrieveListingsRequest { protocol_address: Address::from_str(SEAPORT_V5).ok(), ..Default::default() };
+        assert_eq!(
+            req.to_qs_vec().unwrap(),
+            vec![("protocol_address".to_string(), "0x00000000000000adc04c56bf30ac9d3c0aaf14dc".to_string())]
+        );
+    }
+
+    #[test]
+    fn can_convert_retrieve_listing_request_with_cursor_to_qs() {
+        let req = RetrieveListingsRequest { limit: Some(50), cursor: Some("LXBrPTExNTE5Njk3NjYw".into()), ..Default::default() };
+        assert_eq!(
+            req.to_qs_vec().unwrap(),
+            vec![("limit".to_string(), "50".to_string()), ("cursor".to_string(), "LXBrPTExNTE5Njk3NjYw".to_string())]
+        );
+    }
+
+    #[test]
+    fn can_split_token_ids() {
+        let req = RetrieveListingsRequest { limit: Some(50), token_ids: vec!["1".into(), "22".into(), "333".into()], ..Default::default() };
+        assert_eq!(req.split_token_ids(100).unwrap(), vec![req.clone()]);
+
+        // limit=50 takes 10 characters, each token id 12 to 14
+        let requests = req.split_token_ids(40).unwrap();
+        assert_eq!(requests.iter().map(|req| req.token_ids.clone()).collect::<Vec<_>>(), vec![vec!["1", "22"], vec!["333"]]);
+        assert!(requests.iter().all(|req| req.limit == Some(50)));
+
+        assert!(req.split_token_ids(20).is_err());
+        let req = RetrieveListingsRequest { cursor: Some("cursor".into()), ..req };
+        assert!(req.split_token_ids(40).is_err());
+    }
+
+    #[test]
+    fn can_convert_cheapest_first_to_qs() {
+        let contract = Address::from_str("0xBC4CA0EdA7647A8aB7C2061c2E118A18a936f13D").unwrap();
+        let req = RetrieveListingsRequest::for_token(contract, "1").cheapest_first();
+        let request = reqwest::Client::new().get("https://example.com").query(&req.to_qs_vec().unwrap()).build().unwrap();
+        assert_eq!(
+            request.url().query().unwrap(),
+            "asset_contract_address=0xbc4ca0eda7647a8ab7c2061c2e118a18a936f13d&token_ids=1&order_by=eth_price&order_direction=asc"
+        );
+
+        assert_eq!(OrderOpeningOption::default(), OrderOpeningOption::CreatedDate);
+        assert_eq!(OrderDirection::default(), OrderDirection::Desc);
+    }
+
+    #[test]
+    fn can_convert_order_by_to_qs() {
+        for (order_by, order_direction, expected) in [
+            (OrderOpeningOption::CreatedDate, OrderDirection::Desc, "order_by=created_date&order_direction=desc"),
+            (OrderOpeningOption::EthPrice, OrderDirection::Asc, "order_by=eth_price&order_direction=asc"),
+        ] {
+            let req = RetrieveListingsRequest {
+                order_by: Some(order_by.clone()),
+                order_direction: Some(order_direction.clone()),
+                ..Default::default()
+            };
+            let request = reqwest::Client::new().get("https://example.com").query(&req.to_qs_vec().unwrap()).build().unwrap();
+            assert_eq!(request.url().query().unwrap(), expected);
+
+            let req = RetrieveOffersRequest { order_by: Some(order_by), order_direction: Some(order_direction), ..Default::default() };
+            let request = reqwest::Client::new().get("https://example.com").query(&to_query_pairs(&req).unwrap()).build().unwrap();
+            assert_eq!(request.url().query().unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn can_append_extra_params_to_qs() {
+        let req = RetrieveListingsRequest {
+            limit: Some(10),
+            extra_params: vec![
+                ("include_private".to_string(), "true".to_string()),
+                ("trait".to_string(), "a".to_string()),
+                ("sort".to_string(), "asc".to_string()),
+                ("trait".to_string(), "b".to_string()),
+            ],
+            ..Default::default()
+        };
+        let request = reqwest::Client::new().get("https://example.com").query(&req.to_qs_vec().unwrap()).build().unwrap();
+        assert_eq!(request.url().query().unwrap(), "limit=10&include_private=true&trait=a&sort=asc&trait=b");
+
+        let req = RetrieveOffersRequest::builder().limit(5).raw_param("include_private", "true").build().unwrap();
+        assert_eq!(
+            req.query_pairs().unwrap(),
+            vec![("limit".to_string(), "5".to_string()), ("include_private".to_string(), "true".to_string())]
+        );
+        assert!(ListCollectionsRequest::default().query_pairs().unwrap().is_empty());
+    }
+
+    #[test]
+    fn can_build_retrieve_offers_request() {
+        let contract = Address::from_str("0xBC4CA0EdA7647A8aB7C2061c2E118A18a936f13D").unwrap();
+        let req = RetrieveOffersRequest::builder()
+            .asset_contract_address(contract)
+            .token_ids(["1", "2"])
+            .order_by(OrderOpeningOption::EthPrice)
+            .order_direction(OrderDirection::Desc)
+            .listed_after(Utc.timestamp_opt(1000, 0).unwrap())
+            .listed_before(Utc.timestamp_opt(2000, 0).unwrap())
+            .limit(10)
+            .build()
+            .unwrap();
+        assert_eq!(req.asset_contract_address, Some(contract));
+        assert_eq!(req.token_ids, vec!["1", "2"]);
+        assert_eq!(req.limit, Some(10));
+        assert_eq!(req.listed_before, Some(Utc.timestamp_opt(2000, 0).unwrap()));
+
+        let same_time = Utc.timestamp_opt(1000, 0).unwrap();
+        assert!(RetrieveOffersRequest::builder().listed_after(same_time).listed_before(same_time).build().is_ok());
+        assert_eq!(RetrieveOffersRequest::builder().build().unwrap(), RetrieveOffersRequest::default());
+    }
+
+    #[test]
+    fn retrieve_offers_request_builder_rejects_invalid_requests() {
+        let res = RetrieveOffersRequest::builder()
+            .listed_after(Utc.timestamp_opt(2000, 0).unwrap())
+            .listed_before(Utc.timestamp_opt(1000, 0).unwrap())
+            .build();
+        assert!(matches!(res, Err(OpenSeaApiError::Other(msg)) if msg.contains("listed_after")));
+
+        let res = RetrieveOffersRequest::builder().order_by(OrderOpeningOption::EthPrice).build();
+        assert!(matches!(res, Err(OpenSeaApiError::Other(msg)) if msg.contains("eth_price")));
+    }
+
+    #[test]
+    fn can_map_error_messages_to_detailed_error_codes() {
+        for (message, code) in [
+            ("The order_hash you provided does not exist", OpenSeaDetailedErrorCode::OrderHashDoesNotExist),
+            ("This order can not be fulfilled at this time.", OpenSeaDetailedErrorCode::OrderCannotBeFulfilled),
+            ("Insufficient balance to fulfill the order", OpenSeaDetailedErrorCode::InsufficientBalance),
+            ("The offerer has insufficient funds", OpenSeaDetailedErrorCode::InsufficientBalance),
+            ("Order has expired", OpenSeaDetailedErrorCode::OrderExpired),
+            ("The listing is not active", OpenSeaDetailedErrorCode::ListingNotActive),
+            ("Invalid signature for order 0x01", OpenSeaDetailedErrorCode::InvalidSignature),
+        ] {
+            assert_eq!(OpenSeaDetailedErrorCode::from_message(message), Some(code), "{message}");
+        }
+        assert_eq!(OpenSeaDetailedErrorCode::from_message("Collection not found"), None);
+        assert_eq!(OpenSeaDetailedErrorCode::from_message("The order_hash you provided does not exist!"), None);
+    }
+
+    #[test]
+    fn can_parse_seaport_protocol() {
+        assert_eq!("seaport1.4".parse::<SeaportProtocol>().unwrap(), SeaportProtocol::Seaport1_4);
+        assert_eq!("seaport1.5".parse::<SeaportProtocol>().unwrap(), SeaportProtocol::Seaport1_5);
+        assert_eq!("seaport1.6".parse::<SeaportProtocol>().unwrap(), SeaportProtocol::Seaport1_6);
+        assert!("seaport2.0".parse::<SeaportProtocol>().is_err());
+
+        let protocol: SeaportProtocol = serde_json::from_str(r#""seaport1.6""#).unwrap();
+        assert_eq!(protocol, SeaportProtocol::Seaport1_6);
+        assert_eq!(protocol.to_string(), "seaport1.6");
+    }
+
+    #[test]
+    fn can_get_protocol_version_of_fulfill_listing_response() {
+        for (file, expected) in [
+            ("resources/response_fulfill_listing_1.4.json", ProtocolVersion::V1_4),
+            ("resources/response_fulfill_listing_1.5.json", ProtocolVersion::V1_5),
+            ("resources/response_fulfill_listing_1.6.json", ProtocolVersion::V1_6),
+        ] {
+            let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+            d.push(file);
+            let res = std::fs::read_to_string(d).unwrap();
+            let res: FulfillListingResponse = serde_json::from_str(&res).unwrap();
+            assert_eq!(res.protocol_version(), Some(expected));
+        }
+    }
+
+    #[test]
+    fn can_convert_retrieve_listing_request_for_token_to_qs() {
+        let contract = Address::from_str("0xBC4CA0EdA7647A8aB7C2061c2E118A18a936f13D").unwrap();
+        let req = RetrieveListingsRequest::for_token(contract, "8606");
+        assert_eq!(
+            req.to_qs_vec().unwrap(),
+            vec![
+                ("asset_contract_address".to_string(), "0xbc4ca0eda7647a8ab7c2061c2e118a18a936f13d".to_string()),
+                ("token_ids".to_string(), "8606".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn to_query_pairs_repeats_array_keys_and_skips_nulls() {
+        #[derive(Serialize)]
+        struct Nested {
+            cursor: Option<String>,
+        }
+
+        #[derive(Serialize)]
+        struct Query {
+            limit: Option<u8>,
+            next: Option<String>,
+            token_ids: Vec<u64>,
+            #[serde(flatten)]
+            nested: Nested,
+            inner: Nested,
+        }
+
+        let query = Query {
+            limit: Some(3),
+            next: None,
+            token_ids: vec![1, 2],
+            nested: Nested { cursor: Some("abc".to_string()) },
+            inner: Nested { cursor: None },
+        };
+        let pairs = to_query_pairs(&query).unwrap();
+        assert_eq!(
+            pairs,
+            vec![
+                ("limit".to_string(), "3".to_string()),
+                ("token_ids".to_string(), "1".to_string()),
+                ("token_ids".to_string(), "2".to_string()),
+                ("cursor".to_string(), "abc".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn to_query_pairs_of_empty_request_is_empty() {
+        assert!(to_query_pairs(&GetAllListingsRequest::default()).unwrap().is_empty());
+        assert!(to_query_pairs(&"not an object").is_err());
+
+        let req = GetAllListingsRequest { limit: Some(10), next: Some("cursor".into()), ..Default::default() };
+        assert_eq!(
+            to_query_pairs(&req).unwrap(),
+            vec![("limit".to_string(), "10".to_string()), ("next".to_string(), "cursor".to_string())]
+        );
+    }
+
     #[test]
     fn can_serialize_fulfill_listing_request() {
         let req = FulfillListingRequest {
@@ -0,0 +1,253 @@
+use crate::types::{
+    api::{
+        nfts::Nft,
+        orders::{Currency, Price},
+        Cursor, QueryRequest,
+    },
+    Chain,
+};
+use alloy_primitives::Address;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, TimestampSeconds};
+
+/// The type of an asset event.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventType {
+    All,
+    Sale,
+    Listing,
+    Offer,
+    Transfer,
+    Cancel,
+    Redemption,
+    /// Event types not yet known to this client.
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Query parameters for the events endpoints.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct GetEventsRequest {
+    /// Only show events after this timestamp. Seconds since the Unix epoch.
+    #[serde_as(as = "Option<TimestampSeconds<i64>>")]
+    pub after: Option<DateTime<Utc>>,
+    /// Only show events before this timestamp. Seconds since the Unix epoch.
+    #[serde_as(as = "Option<TimestampSeconds<i64>>")]
+    pub before: Option<DateTime<Utc>>,
+    /// The types of events to filter by, sent as repeated `event_type` params. Empty for events of all types.
+    #[serde(default)]
+    pub event_type: Vec<EventType>,
+    /// Number of events to retrieve
+    pub limit: Option<u8>,
+    /// The cursor for the next page of results
+    pub next: Option<Cursor>,
+    /// Additional query parameters not modeled by this request, appended to the query string verbatim.
+    #[serde(skip)]
+    pub extra_params: Vec<(String, String)>,
+}
+
+impl QueryRequest for GetEventsRequest {
+    fn extra_params(&self) -> &[(String, String)] {
+        &self.extra_params
+    }
+}
+
+/// Response from the events endpoints, newest events first.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct GetEventsResponse {
+    pub asset_events: Vec<AssetEvent>,
+    #[serde(default)]
+    pub next: Option<Cursor>,
+}
+
+/// A single event of an asset e.g. a sale or a listing. Which fields are set depends on the event type.
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct AssetEvent {
+    pub event_type: EventType,
+    /// The hash of the order, set for order based events like listings, offers and sales.
+    pub order_hash: Option<String>,
+    pub chain: Chain,
+    pub protocol_address: Option<String>,
+    /// The NFT involved in a sale or transfer.
+    pub nft: Option<Nft>,
+    /// The NFT involved in a listing or offer.
+    pub asset: Option<Nft>,
+    #[serde(default)]
+    pub quantity: u64,
+    pub maker: Option<Address>,
+    pub taker: Option<Address>,
+    pub seller: Option<Address>,
+    pub buyer: Option<Address>,
+    pub from_address: Option<Address>,
+    pub to_address: Option<Address>,
+    pub payment: Option<EventPayment>,
+    /// The transaction hash for onchain events.
+    pub transaction: Option<String>,
+    #[serde_as(as = "TimestampSeconds<i64>")]
+    pub event_timestamp: DateTime<Utc>,
+    /// When a sale closed.
+    #[serde_as(as = "Option<TimestampSeconds<i64>>")]
+    #[serde(default)]
+    pub closing_date: Option<DateTime<Utc>>,
+    /// When a listing or offer starts.
+    #[serde_as(as = "Option<TimestampSeconds<i64>>")]
+    #[serde(default)]
+    pub start_date: Option<DateTime<Utc>>,
+    /// When a listing or offer expires.
+    #[serde_as(as = "Option<TimestampSeconds<i64>>")]
+    #[serde(default)]
+    pub expiration_date: Option<DateTime<Utc>>,
+}
+
+impl AssetEvent {
+    /// Key identifying an event, used to de-duplicate events across polls.
+    /// Order based events are identified by their order hash, onchain events by their transaction.
+    pub fn dedup_key(&self) -> String {
+        let id = self.order_hash.as_deref().or(self.transaction.as_deref()).unwrap_or_default();
+        let identifier = self.nft.as_ref().or(self.asset.as_ref()).map(|nft| nft.identifier.as_str()).unwrap_or_default();
+        format!("{:?}:{}:{}:{}", self.event_type, id, identifier, self.event_timestamp.timestamp())
+    }
+
+    /// The event as sale, `None` if it is not a sale or lacks the NFT, buyer, seller or payment.
+    pub fn to_sale(&self) -> Option<SaleEvent> {
+        if self.event_type != EventType::Sale {
+            return None;
+        }
+        let nft = self.nft.as_ref()?;
+        let payment = self.payment.as_ref()?;
+        let currency = if payment.symbol.eq_ignore_ascii_case("ETH") { Currency::Eth } else { Currency::Other(payment.symbol.clone()) };
+        Some(SaleEvent {
+            order_hash: self.order_hash.clone(),
+            contract: nft.contract,
+            token_id: nft.identifier.clone(),
+            quantity: self.quantity,
+            seller: self.seller?,
+            buyer: self.buyer?,
+            price: Price { currency, decimals: payment.decimals, value: payment.quantity.clone() },
+            payment_token: payment.token_address,
+            transaction: self.transaction.clone(),
+            timestamp: self.event_timestamp,
+        })
+    }
+}
+
+/// A sale of an NFT, see [`AssetEvent::to_sale`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SaleEvent {
+    pub order_hash: Option<String>,
+    /// The contract of the sold NFT.
+    pub contract: Address,
+    /// The token id of the sold NFT.
+    pub token_id: String,
+    pub quantity: u64,
+    pub seller: Address,
+    pub buyer: Address,
+    /// The total price paid, in base units of the payment token.
+    pub price: Price,
+    /// The payment token, the zero address for ETH.
+    pub payment_token: Address,
+    /// The transaction hash of the sale.
+    pub transaction: Option<String>,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Payment of an event e.g. the price of a sale.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct EventPayment {
+    pub quantity: String,
+    pub token_address: Address,
+    pub decimals: u16,
+    pub symbol: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::api::to_query_pairs;
+    use std::path::PathBuf;
+
+    #[test]
+    fn can_deserialize_get_events_response() {
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("resources/response_get_events.json");
+        let res = std::fs::read_to_string(d).unwrap();
+        let res: GetEventsResponse = serde_json::from_str(&res).unwrap();
+        assert_eq!(res.asset_events.len(), 2);
+
+        let sale = &res.asset_events[0];
+        assert_eq!(sale.event_type, EventType::Sale);
+        assert_eq!(sale.nft.as_ref().unwrap().identifier, "4655");
+        assert_eq!(sale.payment.as_ref().unwrap().quantity, "25000000000000000000");
+        assert_eq!(sale.event_timestamp.timestamp(), 1698562226);
+
+        let listing = &res.asset_events[1];
+        assert_eq!(listing.event_type, EventType::Listing);
+        assert_eq!(listing.asset.as_ref().unwrap().identifier, "1");
+        assert_ne!(sale.dedup_key(), listing.dedup_key());
+    }
+
+    #[test]
+    fn can_convert_sale_events() {
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("resources/response_get_sales.json");
+        let res = std::fs::read_to_string(d).unwrap();
+        let res: GetEventsResponse = serde_json::from_str(&res).unwrap();
+        let sales: Vec<_> = res.asset_events.iter().filter_map(AssetEvent::to_sale).collect();
+        assert_eq!(sales.len(), 2);
+
+        assert_eq!(sales[0].token_id, "4655");
+        assert_eq!(sales[0].price, Price { currency: Currency::Eth, decimals: 18, value: "25000000000000000000".to_string() });
+        assert_eq!(sales[0].payment_token, Address::ZERO);
+        assert_eq!(sales[0].timestamp.timestamp(), 1698562226);
+
+        assert_eq!(sales[1].token_id, "1203");
+        assert_eq!(sales[1].price.currency, Currency::Other("WETH".to_string()));
+        assert_eq!(sales[1].seller, "0x909f0506a372a8aeed6a812d4a04139d5a1a81ea".parse::<Address>().unwrap());
+        assert_eq!(sales[1].buyer, "0x67d58520775af7848f3ee2adaa227435f5a91a04".parse::<Address>().unwrap());
+    }
+
+    #[test]
+    fn listing_event_is_not_a_sale() {
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("resources/response_get_events.json");
+        let res = std::fs::read_to_string(d).unwrap();
+        let res: GetEventsResponse = serde_json::from_str(&res).unwrap();
+        assert!(res.asset_events[0].to_sale().is_some());
+        assert!(res.asset_events[1].to_sale().is_none());
+    }
+
+    #[test]
+    fn can_serialize_event_types_to_query() {
+        let req = GetEventsRequest { event_type: vec![EventType::Sale], limit: Some(10), ..Default::default() };
+        assert_eq!(
+            to_query_pairs(&req).unwrap(),
+            vec![("event_type".to_string(), "sale".to_string()), ("limit".to_string(), "10".to_string())]
+        );
+
+        let req = GetEventsRequest { event_type: vec![EventType::Sale, EventType::Transfer, EventType::Redemption], ..Default::default() };
+        assert_eq!(
+            to_query_pairs(&req).unwrap(),
+            vec![
+                ("event_type".to_string(), "sale".to_string()),
+                ("event_type".to_string(), "transfer".to_string()),
+                ("event_type".to_string(), "redemption".to_string()),
+            ]
+        );
+
+        assert!(to_query_pairs(&GetEventsRequest::default()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn can_deserialize_unknown_event_type() {
+        let event_type: EventType = serde_json::from_str(r#""order""#).unwrap();
+        assert_eq!(event_type, EventType::Other("order".to_string()));
+    }
+}
@@ -0,0 +1,215 @@
+use super::{orders::Order, Cursor, QueryRequest};
+use alloy_primitives::Address;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+/// Query parameters for the endpoints returning a paginated list of NFTs.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct GetNftsRequest {
+    /// Number of NFTs to retrieve (1 - 200)
+    pub limit: Option<u8>,
+    /// The cursor for the next page of results
+    pub next: Option<Cursor>,
+    /// Include the active orders of each NFT in [`Nft::orders`].
+    pub include_orders: Option<bool>,
+    /// Additional query parameters not modeled by this request, appended to the query string verbatim.
+    #[serde(skip)]
+    pub extra_params: Vec<(String, String)>,
+}
+
+impl QueryRequest for GetNftsRequest {
+    fn extra_params(&self) -> &[(String, String)] {
+        &self.extra_params
+    }
+}
+
+/// Response containing a list of NFTs, along with an optional cursor for the next page.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct GetNftsResponse {
+    pub nfts: Vec<Nft>,
+    /// Cursor for the next page. `None` if there are no more NFTs to retrieve.
+    #[serde(default)]
+    pub next: Option<Cursor>,
+}
+
+impl GetNftsResponse {
+    /// NFTs having a trait with the given type and value, both compared case-insensitively. Numeric trait values are
+    /// compared by their string representation. NFTs without traits never match.
+    pub fn filter_by_trait(&self, trait_type: &str, value: &str) -> Vec<&Nft> {
+        self.nfts.iter().filter(|nft| nft.has_trait(trait_type, value)).collect()
+    }
+}
+
+/// Response of the endpoint returning a single NFT.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct NftResponse {
+    pub nft: Nft,
+}
+
+/// A single NFT as returned by the OpenSea V2 NFT endpoints.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Nft {
+    /// The token id of the NFT.
+    pub identifier: String,
+    /// The collection slug the NFT belongs to.
+    pub collection: String,
+    /// The contract address of the NFT.
+    pub contract: Address,
+    /// The token standard e.g. erc721 or erc1155.
+    pub token_standard: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub image_url: Option<String>,
+    pub display_image_url: Option<String>,
+    pub display_animation_url: Option<String>,
+    pub metadata_url: Option<String>,
+    pub opensea_url: Option<String>,
+    pub updated_at: Option<String>,
+    pub is_disabled: bool,
+    pub is_nsfw: bool,
+    /// The traits of the NFT. Only returned by some endpoints.
+    #[serde(default)]
+    pub traits: Option<Vec<Trait>>,
+    /// The active orders of the NFT, only returned if requested with `include_orders`.
+    #[serde(default)]
+    pub orders: Option<Vec<Order>>,
+}
+
+impl Nft {
+    /// Whether the NFT has a trait with the given type and value, see [`GetNftsResponse::filter_by_trait`].
+    pub fn has_trait(&self, trait_type: &str, value: &str) -> bool {
+        self.traits.iter().flatten().any(|t| t.trait_type.eq_ignore_ascii_case(trait_type) && t.value_eq(value))
+    }
+}
+
+/// A trait (attribute) of an NFT.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-schema", serde(deny_unknown_fields))]
+pub struct Trait {
+    pub trait_type: String,
+    pub display_type: Option<String>,
+    pub max_value: Option<String>,
+    /// The value of the trait, a string or a number.
+    pub value: Value,
+}
+
+impl Trait {
+    fn value_eq(&self, value: &str) -> bool {
+        match &self.value {
+            Value::String(s) => s.eq_ignore_ascii_case(value),
+            Value::Number(n) => n.to_string() == value,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::api::to_query_pairs;
+    use std::path::PathBuf;
+    use std::str::FromStr;
+
+    #[test]
+    fn can_deserialize_get_nfts_by_contract_response() {
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("resources/response_get_nfts_by_contract.json");
+        let res = std::fs::read_to_string(d).unwrap();
+        let res: GetNftsResponse = serde_json::from_str(&res).unwrap();
+        assert_eq!(res.nfts.len(), 2);
+        assert_eq!(res.nfts[0].identifier, "1");
+        assert_eq!(res.nfts[0].contract, Address::from_str("0xBC4CA0EdA7647A8aB7C2061c2E118A18a936f13D").unwrap());
+        assert_eq!(res.next, Some(Cursor::from("LXBrPTEwMDAwMDAwMDAw")));
+    }
+
+    #[test]
+    fn can_deserialize_nfts_with_inline_orders() {
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("resources/response_get_nfts_with_orders.json");
+        let res = std::fs::read_to_string(d).unwrap();
+        let res: GetNftsResponse = serde_json::from_str(&res).unwrap();
+        let orders = res.nfts[0].orders.as_ref().unwrap();
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].order_hash.as_deref(), Some("0x33f436f84910921ba9e1f3aa5e318e060b02834cfcc883a97d5f303ce289c39a"));
+
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("resources/response_get_nfts_by_contract.json");
+        let res = std::fs::read_to_string(d).unwrap();
+        let res: GetNftsResponse = serde_json::from_str(&res).unwrap();
+        assert!(res.nfts.iter().all(|nft| nft.orders.is_none()));
+    }
+
+    #[test]
+    fn can_serialize_include_orders() {
+        let req = GetNftsRequest { limit: Some(5), include_orders: Some(true), ..Default::default() };
+        assert_eq!(
+            to_query_pairs(&req).unwrap(),
+            vec![("limit".to_string(), "5".to_string()), ("include_orders".to_string(), "true".to_string())]
+        );
+        assert!(to_query_pairs(&GetNftsRequest::default()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn can_deserialize_empty_get_nfts_response() {
+        let res: GetNftsResponse = serde_json::from_str(r#"{ "nfts": [] }"#).unwrap();
+        assert!(res.nfts.is_empty());
+        assert_eq!(res.next, None);
+
+        let res: GetNftsResponse = serde_json::from_str(r#"{ "nfts": [], "next": null }"#).unwrap();
+        assert!(res.nfts.is_empty());
+        assert_eq!(res.next, None);
+    }
+
+    fn nft_with_traits(identifier: &str, traits: Value) -> Value {
+        serde_json::json!({
+            "identifier": identifier,
+            "collection": "boredapeyachtclub",
+            "contract": "0xbc4ca0eda7647a8ab7c2061c2e118a18a936f13d",
+            "token_standard": "erc721",
+            "name": null,
+            "description": null,
+            "image_url": null,
+            "display_image_url": null,
+            "display_animation_url": null,
+            "metadata_url": null,
+            "opensea_url": null,
+            "updated_at": null,
+            "is_disabled": false,
+            "is_nsfw": false,
+            "traits": traits
+        })
+    }
+
+    #[test]
+    fn can_filter_nfts_by_trait() {
+        let res: GetNftsResponse = serde_json::from_value(serde_json::json!({
+            "nfts": [
+                nft_with_traits("1", serde_json::json!([
+                    { "trait_type": "Fur", "display_type": null, "max_value": null, "value": "Gold" },
+                    { "trait_type": "Eyes", "display_type": null, "max_value": null, "value": "Bored" }
+                ])),
+                nft_with_traits("2", serde_json::json!([
+                    { "trait_type": "Fur", "display_type": null, "max_value": null, "value": "Brown" },
+                    { "trait_type": "Level", "display_type": "number", "max_value": "10", "value": 5 }
+                ])),
+                nft_with_traits("3", Value::Null),
+                nft_with_traits("4", serde_json::json!([
+                    { "trait_type": "fur", "display_type": null, "max_value": null, "value": "gold" }
+                ])),
+            ]
+        }))
+        .unwrap();
+        let ids = |nfts: Vec<&Nft>| nfts.into_iter().map(|nft| nft.identifier.clone()).collect::<Vec<_>>();
+
+        assert_eq!(ids(res.filter_by_trait("Fur", "Gold")), vec!["1", "4"]);
+        assert_eq!(ids(res.filter_by_trait("Level", "5")), vec!["2"]);
+        assert_eq!(ids(res.filter_by_trait("Eyes", "Gold")), Vec::<String>::new());
+        assert!(res.filter_by_trait("Hat", "Crown").is_empty());
+    }
+}
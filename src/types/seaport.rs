@@ -0,0 +1,146 @@
+use alloy_primitives::B256;
+use alloy_sol_types::{sol, Eip712Domain, SolStruct};
+use serde_json::{json, Value};
+
+sol! {
+    /// An item offered by the offerer of a Seaport order.
+    #[derive(Debug, PartialEq, Eq)]
+    struct OfferItem {
+        uint8 itemType;
+        address token;
+        uint256 identifierOrCriteria;
+        uint256 startAmount;
+        uint256 endAmount;
+    }
+
+    /// An item the offerer of a Seaport order receives when it is fulfilled.
+    #[derive(Debug, PartialEq, Eq)]
+    struct ConsiderationItem {
+        uint8 itemType;
+        address token;
+        uint256 identifierOrCriteria;
+        uint256 startAmount;
+        uint256 endAmount;
+        address recipient;
+    }
+
+    /// The components of a Seaport order as signed by the offerer and hashed by `getOrderHash`.
+    #[derive(Debug, PartialEq, Eq)]
+    struct OrderComponents {
+        address offerer;
+        address zone;
+        OfferItem[] offer;
+        ConsiderationItem[] consideration;
+        uint8 orderType;
+        uint256 startTime;
+        uint256 endTime;
+        bytes32 zoneHash;
+        uint256 salt;
+        bytes32 conduitKey;
+        uint256 counter;
+    }
+}
+
+/// EIP-712 typed data of a Seaport order, the domain of the Seaport contract and the order components to sign.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeaportTypedData {
+    pub domain: Eip712Domain,
+    pub message: OrderComponents,
+}
+
+impl SeaportTypedData {
+    /// The hash to sign, `keccak256("\x19\x01" || domainSeparator || hashStruct(message))`.
+    pub fn signing_hash(&self) -> B256 {
+        self.message.eip712_signing_hash(&self.domain)
+    }
+
+    /// The typed data as JSON for `eth_signTypedData_v4`, e.g. to sign the order with a browser or hardware wallet.
+    pub fn to_json(&self) -> Value {
+        let OrderComponents { offerer, zone, offer, consideration, orderType, startTime, endTime, zoneHash, salt, conduitKey, counter } =
+            &self.message;
+        let offer: Vec<_> = offer
+            .iter()
+            .map(|item| {
+                json!({
+                    "itemType": item.itemType,
+                    "token": item.token,
+                    "identifierOrCriteria": item.identifierOrCriteria.to_string(),
+                    "startAmount": item.startAmount.to_string(),
+                    "endAmount": item.endAmount.to_string(),
+                })
+            })
+            .collect();
+        let consideration: Vec<_> = consideration
+            .iter()
+            .map(|item| {
+                json!({
+                    "itemType": item.itemType,
+                    "token": item.token,
+                    "identifierOrCriteria": item.identifierOrCriteria.to_string(),
+                    "startAmount": item.startAmount.to_string(),
+                    "endAmount": item.endAmount.to_string(),
+                    "recipient": item.recipient,
+                })
+            })
+            .collect();
+
+        json!({
+            "types": {
+                "EIP712Domain": [
+                    { "name": "name", "type": "string" },
+                    { "name": "version", "type": "string" },
+                    { "name": "chainId", "type": "uint256" },
+                    { "name": "verifyingContract", "type": "address" },
+                ],
+                "OrderComponents": [
+                    { "name": "offerer", "type": "address" },
+                    { "name": "zone", "type": "address" },
+                    { "name": "offer", "type": "OfferItem[]" },
+                    { "name": "consideration", "type": "ConsiderationItem[]" },
+                    { "name": "orderType", "type": "uint8" },
+                    { "name": "startTime", "type": "uint256" },
+                    { "name": "endTime", "type": "uint256" },
+                    { "name": "zoneHash", "type": "bytes32" },
+                    { "name": "salt", "type": "uint256" },
+                    { "name": "conduitKey", "type": "bytes32" },
+                    { "name": "counter", "type": "uint256" },
+                ],
+                "OfferItem": [
+                    { "name": "itemType", "type": "uint8" },
+                    { "name": "token", "type": "address" },
+                    { "name": "identifierOrCriteria", "type": "uint256" },
+                    { "name": "startAmount", "type": "uint256" },
+                    { "name": "endAmount", "type": "uint256" },
+                ],
+                "ConsiderationItem": [
+                    { "name": "itemType", "type": "uint8" },
+                    { "name": "token", "type": "address" },
+                    { "name": "identifierOrCriteria", "type": "uint256" },
+                    { "name": "startAmount", "type": "uint256" },
+                    { "name": "endAmount", "type": "uint256" },
+                    { "name": "recipient", "type": "address" },
+                ],
+            },
+            "primaryType": "OrderComponents",
+            "domain": {
+                "name": self.domain.name,
+                "version": self.domain.version,
+                "chainId": self.domain.chain_id.map(|chain_id| chain_id.to_string()),
+                "verifyingContract": self.domain.verifying_contract,
+            },
+            "message": {
+                "offerer": offerer,
+                "zone": zone,
+                "offer": offer,
+                "consideration": consideration,
+                "orderType": orderType,
+                "startTime": startTime.to_string(),
+                "endTime": endTime.to_string(),
+                "zoneHash": zoneHash,
+                "salt": salt.to_string(),
+                "conduitKey": conduitKey,
+                "counter": counter.to_string(),
+            },
+        })
+    }
+}
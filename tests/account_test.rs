@@ -0,0 +1,73 @@
+mod common;
+use common::mock_client;
+
+use alloy_primitives::Address;
+use opensea_client_rs::types::OpenSeaApiError;
+use serde_json::json;
+use std::str::FromStr;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn can_resolve_username_to_address() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/accounts/cakevm"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "address": "0x67d58520775af7848f3ee2adaa227435f5a91a04",
+            "username": "cakevm",
+            "profile_image_url": "https://i.seadn.io/profile.png",
+            "banner_image_url": null,
+            "website": null,
+            "social_media_accounts": [{ "platform": "twitter", "username": "cakevm" }],
+            "bio": "",
+            "joined_date": "2021-07-30"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let address = client.resolve_address("cakevm".to_string()).await.unwrap();
+    assert_eq!(address, Address::from_str("0x67d58520775af7848f3ee2adaa227435f5a91a04").unwrap());
+
+    let account = client.get_account("cakevm".to_string()).await.unwrap();
+    assert_eq!(account.username.as_deref(), Some("cakevm"));
+    assert_eq!(account.social_media_accounts[0].platform, "twitter");
+}
+
+#[tokio::test]
+async fn resolve_address_fails_for_unknown_username() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/accounts/unknown"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({ "errors": ["Account not found"] })))
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let res = client.resolve_address("unknown".to_string()).await;
+    assert!(matches!(res, Err(OpenSeaApiError::Other(message)) if message.contains("unknown")));
+}
+
+#[tokio::test]
+async fn resolve_address_keeps_other_errors() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/accounts/missing"))
+        .respond_with(ResponseTemplate::new(404).set_body_string("Not Found"))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/accounts/invalid"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({ "errors": ["Invalid username"] })))
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let res = client.resolve_address("missing".to_string()).await;
+    assert!(matches!(res, Err(OpenSeaApiError::Other(message)) if message.contains("missing")));
+    let res = client.resolve_address("invalid".to_string()).await;
+    assert!(matches!(res, Err(OpenSeaApiError::OpenSeaError(_))));
+}
@@ -0,0 +1,58 @@
+mod common;
+use common::mock_client;
+
+use serde_json::{json, Value};
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn listing_json(order_hash: &str) -> Value {
+    let res = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/resources/response_get_all_listings.json")).unwrap();
+    let mut res: Value = serde_json::from_str(&res).unwrap();
+    let mut listing = res["listings"][0].take();
+    listing["order_hash"] = Value::String(order_hash.to_string());
+    listing
+}
+
+#[tokio::test]
+async fn can_get_best_listings() {
+    let server = MockServer::start().await;
+    for identifier in ["1", "2"] {
+        Mock::given(method("GET"))
+            .and(path(format!("/v2/listings/collection/test-collection/nfts/{identifier}/best")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(listing_json(&format!("0x0{identifier}"))))
+            .mount(&server)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .and(path("/v2/listings/collection/test-collection/nfts/3/best"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({ "errors": ["Listing not found"] })))
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let identifiers = vec!["1".to_string(), "2".to_string(), "3".to_string()];
+    let mut res = client.get_best_listings("test-collection".to_string(), identifiers, 2).await;
+    res.sort_by(|a, b| a.0.cmp(&b.0));
+
+    assert_eq!(res.len(), 3);
+    assert_eq!(res[0].1.as_ref().unwrap().as_ref().unwrap().order_hash, "0x01");
+    assert_eq!(res[1].1.as_ref().unwrap().as_ref().unwrap().order_hash, "0x02");
+    assert_eq!(res[2].0, "3");
+    assert!(res[2].1.as_ref().unwrap().is_none());
+}
+
+#[tokio::test]
+async fn get_best_listing_of_unlisted_nft_returns_none() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/listings/collection/test-collection/nfts/1/best"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let res = client.get_best_listing("test-collection".to_string(), "1".to_string()).await.unwrap();
+    assert!(res.is_none());
+}
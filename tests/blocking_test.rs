@@ -0,0 +1,49 @@
+#![cfg(feature = "blocking")]
+
+mod common;
+use common::order_json;
+
+use opensea_client_rs::{types::api::RetrieveListingsRequest, OpenSeaApiConfig, OpenSeaV2ClientBlocking};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+/// The blocking client must not run inside an async runtime, so it is called from a separate thread.
+fn blocking_client(base_url: String) -> OpenSeaV2ClientBlocking {
+    OpenSeaV2ClientBlocking::new(OpenSeaApiConfig { base_url: Some(base_url), ..Default::default() })
+}
+
+#[tokio::test]
+async fn can_retrieve_listings_blocking() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/orders/ethereum/seaport/listings"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "next": null,
+            "previous": null,
+            "orders": [order_json("0x01")]
+        })))
+        .mount(&server)
+        .await;
+
+    let uri = server.uri();
+    let res = std::thread::spawn(move || blocking_client(uri).retrieve_listings(RetrieveListingsRequest::default())).join().unwrap();
+    assert_eq!(res.unwrap().orders[0].order_hash.as_deref(), Some("0x01"));
+}
+
+#[tokio::test]
+async fn can_get_collection_blocking() {
+    let server = MockServer::start().await;
+    let body = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/resources/response_get_collection.json")).unwrap();
+    Mock::given(method("GET"))
+        .and(path("/v2/collections/sheboshis"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+        .mount(&server)
+        .await;
+
+    let uri = server.uri();
+    let res = std::thread::spawn(move || blocking_client(uri).get_collection("sheboshis".to_string())).join().unwrap();
+    assert_eq!(res.unwrap().collection, "sheboshis");
+}
@@ -0,0 +1,53 @@
+mod common;
+use common::mock_client;
+
+use alloy_primitives::{Address, B256};
+use opensea_client_rs::types::{api::OpenSeaDetailedErrorCode, Chain, OpenSeaApiError};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const PROTOCOL_ADDRESS: &str = "0x0000000000000068F116a894984e2DB1123eB395";
+
+fn cancel_path(order_hash: &B256) -> String {
+    format!("/v2/orders/chain/ethereum/protocol/{PROTOCOL_ADDRESS}/{order_hash}/cancel")
+}
+
+#[tokio::test]
+async fn can_cancel_orders() {
+    let server = MockServer::start().await;
+    let (ok_hash, err_hash) = (B256::repeat_byte(1), B256::repeat_byte(2));
+    Mock::given(method("POST"))
+        .and(path(cancel_path(&ok_hash)))
+        .and(body_json(json!({ "offerer_signature": "0x01" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "last_signature_issued_valid_until": "2024-03-14T10:39:37Z" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(cancel_path(&err_hash)))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({ "errors": ["Invalid signature"] })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let protocol_address: Address = PROTOCOL_ADDRESS.parse().unwrap();
+    let res = client.cancel_orders(Chain::Ethereum, protocol_address, vec![ok_hash, err_hash], vec!["0x01".into(), "0x02".into()]).await;
+    assert_eq!(res.len(), 2);
+    assert!(res[0].is_ok());
+    assert!(matches!(res[1], Err(OpenSeaApiError::OpenSeaDetailedError(OpenSeaDetailedErrorCode::InvalidSignature))));
+}
+
+#[tokio::test]
+async fn cancel_orders_requires_a_signature_per_order() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST")).respond_with(ResponseTemplate::new(200)).expect(0).mount(&server).await;
+
+    let client = mock_client(server.uri());
+    let protocol_address: Address = PROTOCOL_ADDRESS.parse().unwrap();
+    let res = client.cancel_orders(Chain::Ethereum, protocol_address, vec![B256::ZERO, B256::ZERO], vec!["0x01".into()]).await;
+    assert!(matches!(res.as_slice(), [Err(OpenSeaApiError::Other(_))]));
+}
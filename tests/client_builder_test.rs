@@ -0,0 +1,78 @@
+use opensea_client_rs::{OpenSeaApiConfig, OpenSeaV2Client};
+use reqwest::{
+    header::{HeaderMap, HeaderValue},
+    ClientBuilder,
+};
+use serde_json::json;
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::TcpListener,
+};
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn from_builder_keeps_custom_and_api_key_headers() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/collections/test-collection/stats"))
+        .and(header("X-API-KEY", "test-key"))
+        .and(header("X-Custom", "custom-value"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total": {
+                "volume": 0.0,
+                "sales": 0,
+                "average_price": 0.0,
+                "num_owners": 0,
+                "market_cap": 0.0,
+                "floor_price": null,
+                "floor_price_symbol": null
+            },
+            "intervals": []
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut headers = HeaderMap::new();
+    headers.insert("X-Custom", HeaderValue::from_static("custom-value"));
+    let builder = ClientBuilder::new().default_headers(headers);
+    let cfg = OpenSeaApiConfig { api_key: Some("test-key".to_string()), base_url: Some(server.uri()), ..Default::default() };
+    let client = OpenSeaV2Client::from_builder(cfg, builder).unwrap();
+
+    client.get_collection_stats("test-collection".to_string()).await.unwrap();
+}
+
+#[test]
+fn from_builder_rejects_invalid_api_key() {
+    let cfg = OpenSeaApiConfig { api_key: Some("invalid\nkey".to_string()), ..Default::default() };
+    assert!(OpenSeaV2Client::from_builder(cfg, ClientBuilder::new()).is_err());
+}
+
+#[tokio::test]
+async fn from_builder_applies_http1_only() {
+    // Answer a single request over plain TCP and return its request line
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let server = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut request_line = String::new();
+        BufReader::new(&stream).read_line(&mut request_line).unwrap();
+        let body = r#"{"total":{"volume":0.0,"sales":0,"average_price":0.0,"num_owners":0,"market_cap":0.0},"intervals":[]}"#;
+        let res = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        stream.write_all(res.as_bytes()).unwrap();
+        request_line
+    });
+
+    // HTTP/2 prior knowledge of the builder is overridden by the config
+    let cfg = OpenSeaApiConfig { base_url: Some(base_url), http1_only: true, ..Default::default() };
+    let client = OpenSeaV2Client::from_builder(cfg, ClientBuilder::new().http2_prior_knowledge()).unwrap();
+    client.get_collection_stats("test-collection".to_string()).await.unwrap();
+
+    assert_eq!(server.join().unwrap().trim_end(), "GET /v2/collections/test-collection/stats HTTP/1.1");
+}
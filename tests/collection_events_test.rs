@@ -0,0 +1,158 @@
+mod common;
+use common::mock_client;
+
+use futures::StreamExt;
+use opensea_client_rs::types::api::events::EventType;
+use serde_json::json;
+use std::time::Duration;
+use wiremock::{
+    matchers::{method, path, query_param, query_param_is_missing},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn sale(order_hash: &str, event_timestamp: i64) -> serde_json::Value {
+    json!({
+        "event_type": "sale",
+        "order_hash": order_hash,
+        "chain": "ethereum",
+        "protocol_address": "0x0000000000000068f116a894984e2db1123eb395",
+        "quantity": 1,
+        "seller": "0x67d58520775af7848f3ee2adaa227435f5a91a04",
+        "buyer": "0x193d3eda0dbabd55453de814ef08a6255446c911",
+        "event_timestamp": event_timestamp
+    })
+}
+
+#[tokio::test]
+async fn can_stream_new_collection_events() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/events/collection/test-collection"))
+        .and(query_param("event_type", "sale"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "asset_events": [sale("0x01", 1000)] })))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/events/collection/test-collection"))
+        .and(query_param("after", "1000"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "asset_events": [sale("0x02", 1100), sale("0x01", 1000)] })))
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let events: Vec<_> =
+        client.stream_collection_events("test-collection".to_string(), EventType::Sale, Duration::from_millis(10)).take(2).collect().await;
+
+    let order_hashes: Vec<_> = events.into_iter().map(|event| event.unwrap().order_hash.unwrap()).collect();
+    assert_eq!(order_hashes, vec!["0x01", "0x02"]);
+}
+
+#[tokio::test]
+async fn collection_events_stream_follows_pages_of_a_poll() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/events/collection/test-collection"))
+        .and(query_param_is_missing("after"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "asset_events": [sale("0x01", 1000)], "next": "older" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/events/collection/test-collection"))
+        .and(query_param("after", "1000"))
+        .and(query_param_is_missing("next"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "asset_events": [sale("0x04", 1300), sale("0x03", 1200)], "next": "page2" })),
+        )
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/events/collection/test-collection"))
+        .and(query_param("next", "page2"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "asset_events": [sale("0x02", 1100), sale("0x01", 1000)], "next": "page3" })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/events/collection/test-collection"))
+        .and(query_param("next", "page3"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let events: Vec<_> =
+        client.stream_collection_events("test-collection".to_string(), EventType::Sale, Duration::from_millis(10)).take(4).collect().await;
+
+    let order_hashes: Vec<_> = events.into_iter().map(|event| event.unwrap().order_hash.unwrap()).collect();
+    assert_eq!(order_hashes, vec!["0x01", "0x02", "0x03", "0x04"]);
+}
+
+#[tokio::test]
+async fn collection_events_stream_ends_on_shutdown() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/events/collection/test-collection"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "asset_events": [sale("0x01", 1000)] })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let mut events =
+        std::pin::pin!(client.stream_collection_events("test-collection".to_string(), EventType::Sale, Duration::from_secs(60)));
+    assert_eq!(events.next().await.unwrap().unwrap().order_hash.unwrap(), "0x01");
+
+    // The stream is waiting for the next poll, which must not delay the shutdown
+    let shutdown = async {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        client.shutdown();
+    };
+    let (next, _) = tokio::time::timeout(Duration::from_secs(5), futures::future::join(events.next(), shutdown)).await.unwrap();
+    assert!(next.is_none());
+}
+
+#[tokio::test]
+async fn can_wait_for_fill() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/events/collection/test-collection"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "asset_events": [sale("0x01", 1000)] })))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/events/collection/test-collection"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "asset_events": [sale("0xABCD", 1100), sale("0x01", 1000)] })))
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let event = client
+        .wait_for_fill("test-collection".to_string(), "0xabcd".to_string(), Duration::from_secs(5), Duration::from_millis(10))
+        .await
+        .unwrap();
+    assert_eq!(event.unwrap().order_hash.unwrap(), "0xABCD");
+}
+
+#[tokio::test]
+async fn wait_for_fill_returns_none_on_timeout() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/events/collection/test-collection"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "asset_events": [sale("0x01", 1000)] })))
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let event = client
+        .wait_for_fill("test-collection".to_string(), "0x02".to_string(), Duration::from_millis(100), Duration::from_millis(10))
+        .await
+        .unwrap();
+    assert!(event.is_none());
+}
@@ -0,0 +1,58 @@
+mod common;
+use common::mock_client;
+
+use alloy_primitives::Address;
+use opensea_client_rs::types::{Chain, OpenSeaApiError};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const CONTRACT: &str = "0xBC4CA0EdA7647A8aB7C2061c2E118A18a936f13D";
+
+fn fixture(name: &str) -> String {
+    std::fs::read_to_string(format!("{}/resources/{name}", env!("CARGO_MANIFEST_DIR"))).unwrap()
+}
+
+#[tokio::test]
+async fn can_get_collection_for_contract() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/v2/chain/ethereum/contract/{CONTRACT}")))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(fixture("response_get_contract.json"), "application/json"))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/collections/boredapeyachtclub"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(fixture("response_get_collection.json"), "application/json"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let collection = client.collection_for_contract(Chain::Ethereum, CONTRACT.parse().unwrap()).await.unwrap();
+    assert_eq!(collection.collection, "sheboshis");
+}
+
+#[tokio::test]
+async fn collection_for_contract_without_collection_fails() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/v2/chain/ethereum/contract/{CONTRACT}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "address": CONTRACT,
+            "chain": "ethereum",
+            "collection": null,
+            "contract_standard": "erc721",
+            "name": "Unknown"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let address: Address = CONTRACT.parse().unwrap();
+    let res = client.collection_for_contract(Chain::Ethereum, address).await;
+    assert!(matches!(res, Err(OpenSeaApiError::Other(msg)) if msg.contains("has no collection")));
+}
@@ -0,0 +1,142 @@
+mod common;
+use common::mock_client;
+
+use opensea_client_rs::types::api::orders::Currency;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn stats(floor_price: f64) -> serde_json::Value {
+    json!({
+        "total": {
+            "volume": 100.0,
+            "sales": 10,
+            "average_price": 10.0,
+            "num_owners": 5,
+            "market_cap": 50.0,
+            "floor_price": floor_price,
+            "floor_price_symbol": "ETH"
+        },
+        "intervals": []
+    })
+}
+
+#[tokio::test]
+async fn can_get_collection_stats_batch() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/collections/first/stats"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(stats(1.0)))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/collections/second/stats"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(stats(2.0)))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/collections/missing/stats"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({ "errors": ["Collection not found"] })))
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let slugs = vec!["first".to_string(), "missing".to_string(), "second".to_string()];
+    let mut res = client.get_collection_stats_batch(slugs, 2).await;
+    res.sort_by(|a, b| a.0.cmp(&b.0));
+
+    assert_eq!(res.len(), 3);
+    assert_eq!(res[0].0, "first");
+    assert_eq!(res[0].1.as_ref().unwrap().total.floor_price.as_ref().and_then(serde_json::Number::as_f64), Some(1.0));
+    assert_eq!(res[1].0, "missing");
+    assert!(res[1].1.is_err());
+    assert_eq!(res[2].0, "second");
+    assert_eq!(res[2].1.as_ref().unwrap().total.floor_price.as_ref().and_then(serde_json::Number::as_f64), Some(2.0));
+}
+
+#[tokio::test]
+async fn can_get_floor_price() {
+    let server = MockServer::start().await;
+    let body = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/resources/response_get_collection_stats.json")).unwrap();
+    Mock::given(method("GET"))
+        .and(path("/v2/collections/boredapeyachtclub/stats"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let price = client.get_floor_price("boredapeyachtclub".to_string()).await.unwrap().unwrap();
+    assert_eq!(price.currency, Currency::Eth);
+    assert_eq!(price.decimals, 18);
+    assert_eq!(price.value, "11300000000000000000");
+}
+
+#[tokio::test]
+async fn get_floor_price_without_floor() {
+    let server = MockServer::start().await;
+    let mut body = stats(0.0);
+    body["total"]["floor_price"] = serde_json::Value::Null;
+    body["total"]["floor_price_symbol"] = serde_json::Value::Null;
+    Mock::given(method("GET"))
+        .and(path("/v2/collections/no-floor/stats"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    assert_eq!(client.get_floor_price("no-floor".to_string()).await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn get_floor_price_keeps_all_digits() {
+    let server = MockServer::start().await;
+    let mut body = stats(0.0);
+    body["total"]["floor_price"] = serde_json::from_str("0.123456789012345678").unwrap();
+    Mock::given(method("GET"))
+        .and(path("/v2/collections/exact/stats"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let price = client.get_floor_price("exact".to_string()).await.unwrap().unwrap();
+    assert_eq!(price.value, "123456789012345678");
+}
+
+#[tokio::test]
+async fn get_floor_price_uses_decimals_of_payment_token() {
+    let server = MockServer::start().await;
+    let mut body = stats(0.0);
+    body["total"]["floor_price"] = serde_json::from_str("12.345678").unwrap();
+    body["total"]["floor_price_symbol"] = json!("USDC");
+    Mock::given(method("GET"))
+        .and(path("/v2/collections/usdc/stats"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(&server)
+        .await;
+    let collection = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/resources/response_get_collection.json")).unwrap();
+    let mut collection: serde_json::Value = serde_json::from_str(&collection).unwrap();
+    collection["payment_tokens"] = json!([{
+        "symbol": "USDC",
+        "address": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+        "chain": "ethereum",
+        "image": null,
+        "name": "USD Coin",
+        "decimals": 6,
+        "eth_price": "0.000400000000000",
+        "usd_price": "1.000000000000000000"
+    }]);
+    Mock::given(method("GET"))
+        .and(path("/v2/collections/usdc"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(collection))
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let price = client.get_floor_price("usdc".to_string()).await.unwrap().unwrap();
+    assert_eq!(price.currency, Currency::Other("USDC".to_string()));
+    assert_eq!(price.decimals, 6);
+    assert_eq!(price.value, "12345678");
+}
@@ -0,0 +1,79 @@
+mod common;
+use common::mock_client;
+
+use alloy_primitives::{address, Address};
+use opensea_client_rs::types::{
+    api::{ListCollectionsRequest, TrendingPeriod},
+    Chain, OpenSeaApiError,
+};
+use serde_json::{json, Value};
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: Address = address!("c7d0445ac2947760b3dd388b8586adf079972bf3");
+
+async fn mount_account(server: &MockServer, username: Value) {
+    Mock::given(method("GET"))
+        .and(path(format!("/v2/accounts/{OWNER}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "address": OWNER, "username": username })))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn can_get_collections_by_owner() {
+    let server = MockServer::start().await;
+    mount_account(&server, json!("shib")).await;
+    let res = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/resources/response_get_collections.json")).unwrap();
+    Mock::given(method("GET"))
+        .and(path("/v2/collections"))
+        .and(query_param("creator_username", "shib"))
+        .and(query_param("limit", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(res, "application/json"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let req = ListCollectionsRequest { limit: Some(2), ..Default::default() };
+    let res = client.get_collections_by_owner(OWNER, req).await.unwrap();
+    assert_eq!(res.collections.len(), 2);
+    assert!(res.collections.iter().all(|collection| collection.owner == Some(OWNER)));
+}
+
+#[tokio::test]
+async fn get_collections_by_owner_requires_username() {
+    let server = MockServer::start().await;
+    mount_account(&server, Value::Null).await;
+
+    let client = mock_client(server.uri());
+    let err = client.get_collections_by_owner(OWNER, ListCollectionsRequest::default()).await.unwrap_err();
+    assert!(matches!(err, OpenSeaApiError::Other(ref msg) if msg.contains("has no username")), "{err}");
+}
+
+#[tokio::test]
+async fn can_get_trending_collections() {
+    let server = MockServer::start().await;
+    let res = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/resources/response_get_collections.json")).unwrap();
+    Mock::given(method("GET"))
+        .and(path("/v2/collections"))
+        .and(query_param("chain", "ethereum"))
+        .and(query_param("order_by", "seven_day_change"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(res, "application/json"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let res = client.get_trending_collections(Chain::Ethereum, TrendingPeriod::SevenDays).await.unwrap();
+    assert_eq!(res.collections.len(), 2);
+    assert_eq!(res.collections[0].collection, "sheboshis");
+    assert!(res.next.is_some());
+
+    let err = client.get_trending_collections(Chain::Ethereum, TrendingPeriod::ThirtyDays).await.unwrap_err();
+    assert!(matches!(err, OpenSeaApiError::Other(_)));
+    assert!(client.get_trending_collections(Chain::Sepolia, TrendingPeriod::OneDay).await.is_err());
+}
@@ -1,5 +1,7 @@
 use opensea_client_rs::{types::Chain, OpenSeaApiConfig, OpenSeaV2Client};
+use serde_json::Value;
 
+#[allow(dead_code)]
 pub fn test_client() -> OpenSeaV2Client {
     let cfg = OpenSeaApiConfig { chain: Chain::Goerli, ..Default::default() };
 
@@ -12,3 +14,20 @@ pub fn live_client() -> OpenSeaV2Client {
 
     OpenSeaV2Client::new(cfg)
 }
+
+#[allow(dead_code)]
+pub fn mock_client(base_url: String) -> OpenSeaV2Client {
+    let cfg = OpenSeaApiConfig { base_url: Some(base_url), ..Default::default() };
+
+    OpenSeaV2Client::new(cfg)
+}
+
+/// An order from the listings fixture with the given order hash.
+#[allow(dead_code)]
+pub fn order_json(order_hash: &str) -> Value {
+    let res = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/resources/response_get_listings.json")).unwrap();
+    let mut res: Value = serde_json::from_str(&res).unwrap();
+    let mut order = res["orders"][0].take();
+    order["order_hash"] = Value::String(order_hash.to_string());
+    order
+}
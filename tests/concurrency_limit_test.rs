@@ -0,0 +1,53 @@
+use opensea_client_rs::{OpenSeaApiConfig, OpenSeaV2Client};
+use serde_json::json;
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use wiremock::{
+    matchers::{method, path_regex},
+    Mock, MockServer, Request, Respond, ResponseTemplate,
+};
+
+const DELAY: Duration = Duration::from_millis(100);
+
+/// Records when each request arrived and answers after a fixed delay.
+struct RecordingResponder {
+    arrivals: Arc<Mutex<Vec<Instant>>>,
+}
+
+impl Respond for RecordingResponder {
+    fn respond(&self, _: &Request) -> ResponseTemplate {
+        self.arrivals.lock().unwrap().push(Instant::now());
+        let body = json!({
+            "total": { "volume": 1.0, "sales": 1, "average_price": 1.0, "num_owners": 1, "market_cap": 1.0, "floor_price": 1.0 },
+            "intervals": []
+        });
+        ResponseTemplate::new(200).set_body_json(body).set_delay(DELAY)
+    }
+}
+
+#[tokio::test]
+async fn limits_requests_in_flight_across_calls() {
+    let server = MockServer::start().await;
+    let arrivals = Arc::new(Mutex::new(Vec::new()));
+    Mock::given(method("GET"))
+        .and(path_regex("^/v2/collections/[^/]+/stats$"))
+        .respond_with(RecordingResponder { arrivals: arrivals.clone() })
+        .mount(&server)
+        .await;
+
+    let limit = 2;
+    let cfg = OpenSeaApiConfig { base_url: Some(server.uri()), max_concurrent_requests: Some(limit), ..Default::default() };
+    let client = OpenSeaV2Client::new(cfg);
+    let slugs = |prefix: &str| (0..4).map(|i| format!("{prefix}{i}")).collect::<Vec<_>>();
+    let (first, second) = tokio::join!(client.get_collection_stats_batch(slugs("a"), 8), client.get_collection_stats_batch(slugs("b"), 8));
+    assert!(first.into_iter().chain(second).all(|(_, res)| res.is_ok()));
+
+    // A request can only start once one of the `limit` requests before it has been answered
+    let arrivals = arrivals.lock().unwrap();
+    assert_eq!(arrivals.len(), 8);
+    for window in arrivals.windows(limit + 1) {
+        assert!(window[limit] - window[0] >= DELAY, "more than {limit} requests in flight");
+    }
+}
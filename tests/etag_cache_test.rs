@@ -0,0 +1,95 @@
+#![cfg(feature = "cache")]
+mod common;
+use common::mock_client;
+
+use opensea_client_rs::{OpenSeaApiConfig, OpenSeaV2Client};
+use std::time::Duration;
+
+use wiremock::{
+    matchers::{header, method, path, path_regex},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn returns_cached_collection_on_not_modified() {
+    let body = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/resources/response_get_collection.json")).unwrap();
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/collections/sheboshis"))
+        .and(header("If-None-Match", "\"etag-1\""))
+        .respond_with(ResponseTemplate::new(304))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/collections/sheboshis"))
+        .respond_with(ResponseTemplate::new(200).insert_header("ETag", "\"etag-1\"").set_body_raw(body, "application/json"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let first = client.get_collection("sheboshis".to_string()).await.unwrap();
+    let second = client.get_collection("sheboshis".to_string()).await.unwrap();
+
+    assert_eq!(first.name, "Sheboshis");
+    assert_eq!(second.name, first.name);
+    assert_eq!(second.created_date, first.created_date);
+}
+
+#[tokio::test]
+async fn returns_cached_collection_within_ttl() {
+    let body = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/resources/response_get_collection.json")).unwrap();
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/collections/sheboshis"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/collections/sheboshis"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let cfg = OpenSeaApiConfig { base_url: Some(server.uri()), collection_cache_ttl: Some(Duration::from_secs(60)), ..Default::default() };
+    let client = OpenSeaV2Client::new(cfg);
+    let first = client.get_collection("sheboshis".to_string()).await.unwrap();
+    let second = client.get_collection("sheboshis".to_string()).await.unwrap();
+
+    assert_eq!(second.name, first.name);
+    assert_eq!(second.created_date, first.created_date);
+}
+
+#[tokio::test]
+async fn evicts_least_recently_fetched_response_beyond_limit() {
+    let body = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/resources/response_get_collection.json")).unwrap();
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex("^/v2/collections/[a-z]+$"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+        .expect(3)
+        .mount(&server)
+        .await;
+
+    let cfg = OpenSeaApiConfig {
+        base_url: Some(server.uri()),
+        collection_cache_ttl: Some(Duration::from_secs(60)),
+        max_cached_responses: Some(1),
+        ..Default::default()
+    };
+    let client = OpenSeaV2Client::new(cfg);
+    client.get_collection("first".to_string()).await.unwrap();
+    client.get_collection("second".to_string()).await.unwrap();
+    // Served from the cache
+    client.get_collection("second".to_string()).await.unwrap();
+    // Evicted by the second collection
+    client.get_collection("first".to_string()).await.unwrap();
+}
@@ -0,0 +1,69 @@
+mod common;
+use common::mock_client;
+
+use alloy_primitives::{Address, B256};
+use opensea_client_rs::types::{
+    api::{FulfillListingRequest, FulfillListingResponse, OpenSeaDetailedErrorCode, ProtocolVersion},
+    Chain, OpenSeaApiError,
+};
+use serde_json::{json, Value};
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn request() -> FulfillListingRequest {
+    FulfillListingRequest::builder()
+        .order_hash(B256::with_last_byte(1))
+        .chain(Chain::Ethereum)
+        .protocol_version(ProtocolVersion::V1_6)
+        .fulfiller(Address::with_last_byte(2))
+        .build()
+        .unwrap()
+}
+
+async fn fulfill_with_response(response: ResponseTemplate) -> Result<FulfillListingResponse, OpenSeaApiError> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v2/listings/fulfillment_data"))
+        .and(body_partial_json(json!({ "listing": { "hash": B256::with_last_byte(1), "chain": "ethereum" } })))
+        .respond_with(response)
+        .expect(1)
+        .mount(&server)
+        .await;
+    mock_client(server.uri()).fulfill_listing(request()).await
+}
+
+fn bad_request(message: &str) -> ResponseTemplate {
+    ResponseTemplate::new(400).set_body_json(json!({ "errors": [message] }))
+}
+
+#[tokio::test]
+async fn fulfill_listing_maps_order_hash_does_not_exist() {
+    let err = fulfill_with_response(bad_request("The order_hash you provided does not exist")).await.unwrap_err();
+    assert!(matches!(err, OpenSeaApiError::OpenSeaDetailedError(OpenSeaDetailedErrorCode::OrderHashDoesNotExist)), "{err:?}");
+}
+
+#[tokio::test]
+async fn fulfill_listing_maps_order_cannot_be_fulfilled() {
+    let err = fulfill_with_response(bad_request("This order can not be fulfilled at this time.")).await.unwrap_err();
+    assert!(matches!(err, OpenSeaApiError::OpenSeaDetailedError(OpenSeaDetailedErrorCode::OrderCannotBeFulfilled)), "{err:?}");
+}
+
+#[tokio::test]
+async fn fulfill_listing_keeps_unknown_errors() {
+    let err = fulfill_with_response(bad_request("Something unexpected happened")).await.unwrap_err();
+    assert!(
+        matches!(err, OpenSeaApiError::OpenSeaError(ref res) if res.errors == vec!["Something unexpected happened".to_string()]),
+        "{err:?}"
+    );
+}
+
+#[tokio::test]
+async fn fulfill_listing_returns_fulfillment_data() {
+    let res = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/resources/response_fulfill_listing_1.6.json")).unwrap();
+    let res: Value = serde_json::from_str(&res).unwrap();
+    let res = fulfill_with_response(ResponseTemplate::new(200).set_body_json(res)).await.unwrap();
+    assert_eq!(res.protocol, "seaport1.6");
+    assert!(res.is_onchain());
+}
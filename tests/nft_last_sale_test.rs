@@ -0,0 +1,77 @@
+mod common;
+use common::mock_client;
+
+use alloy_primitives::{address, Address};
+use opensea_client_rs::types::Chain;
+use serde_json::{json, Value};
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const CONTRACT: Address = address!("bc4ca0eda7647a8ab7c2061c2e118a18a936f13d");
+
+fn nft_json() -> Value {
+    let res = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/resources/response_get_nfts_by_contract.json")).unwrap();
+    let mut res: Value = serde_json::from_str(&res).unwrap();
+    res["nfts"][0].take()
+}
+
+async fn mount_nft(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path(format!("/v2/chain/ethereum/contract/{CONTRACT}/nfts/1")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "nft": nft_json() })))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn can_get_nft_with_last_sale() {
+    let server = MockServer::start().await;
+    mount_nft(&server).await;
+    let sale = json!({
+        "event_type": "sale",
+        "order_hash": "0x1234",
+        "chain": "ethereum",
+        "quantity": 1,
+        "seller": "0x909f0506a372a8aeed6a812d4a04139d5a1a81ea",
+        "buyer": "0x67d58520775af7848f3ee2adaa227435f5a91a04",
+        "nft": nft_json(),
+        "payment": { "quantity": "30000000000000000000", "token_address": "0x0000000000000000000000000000000000000000", "decimals": 18, "symbol": "ETH" },
+        "transaction": "0xabcd",
+        "event_timestamp": 1698562226
+    });
+    Mock::given(method("GET"))
+        .and(path(format!("/v2/events/chain/ethereum/contract/{CONTRACT}/nfts/1")))
+        .and(query_param("event_type", "sale"))
+        .and(query_param("limit", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "asset_events": [sale], "next": null })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let (nft, last_sale) = client.get_nft_with_last_sale(Chain::Ethereum, CONTRACT, "1".to_string()).await.unwrap();
+    assert_eq!(nft.identifier, "1");
+    let last_sale = last_sale.unwrap();
+    assert_eq!(last_sale.token_id, "1");
+    assert_eq!(last_sale.price.value, "30000000000000000000");
+    assert_eq!(last_sale.timestamp.timestamp(), 1698562226);
+}
+
+#[tokio::test]
+async fn nft_without_sales_has_no_last_sale() {
+    let server = MockServer::start().await;
+    mount_nft(&server).await;
+    Mock::given(method("GET"))
+        .and(path(format!("/v2/events/chain/ethereum/contract/{CONTRACT}/nfts/1")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "asset_events": [] })))
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let (nft, last_sale) = client.get_nft_with_last_sale(Chain::Ethereum, CONTRACT, "1".to_string()).await.unwrap();
+    assert_eq!(nft.contract, CONTRACT);
+    assert!(last_sale.is_none());
+}
@@ -0,0 +1,46 @@
+mod common;
+use common::{mock_client, order_json};
+
+use opensea_client_rs::types::api::orders::Order;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ORDER_HASH: &str = "0x33f436f84910921ba9e1f3aa5e318e060b02834cfcc883a97d5f303ce289c39a";
+
+#[tokio::test]
+async fn can_refresh_order() {
+    let server = MockServer::start().await;
+    let mut updated = order_json(ORDER_HASH);
+    updated["cancelled"] = json!(true);
+    updated["remaining_quantity"] = json!(0);
+    Mock::given(method("GET"))
+        .and(path(format!("/v2/orders/chain/ethereum/protocol/0x00000000000000ADc04C56Bf30aC9d3c0aAF14dC/{ORDER_HASH}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "order": updated })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let order: Order = serde_json::from_value(order_json(ORDER_HASH)).unwrap();
+    assert!(!order.cancelled);
+
+    let refreshed = order.refresh(&client).await.unwrap();
+    assert!(refreshed.cancelled);
+    assert_eq!(refreshed.remaining_quantity, 0);
+    assert_eq!(refreshed.order_hash, order.order_hash);
+}
+
+#[tokio::test]
+async fn refresh_requires_order_hash() {
+    let server = MockServer::start().await;
+    let client = mock_client(server.uri());
+    let mut order: Order = serde_json::from_value(order_json(ORDER_HASH)).unwrap();
+    order.order_hash = None;
+
+    let err = order.refresh(&client).await.unwrap_err();
+    assert!(err.to_string().contains("no order hash"), "{err}");
+    assert!(server.received_requests().await.unwrap().is_empty());
+}
@@ -0,0 +1,54 @@
+mod common;
+use common::{mock_client, order_json};
+
+use opensea_client_rs::types::{
+    api::{orders::Order, PostOrderRequest},
+    Chain,
+};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn post_order_request(signature: String) -> PostOrderRequest {
+    let order: Order = serde_json::from_value(order_json("0x01")).unwrap();
+    PostOrderRequest {
+        parameters: order.protocol_data.parameters,
+        signature,
+        protocol_address: "0x0000000000000068f116a894984e2db1123eb395".parse().unwrap(),
+    }
+}
+
+#[tokio::test]
+async fn post_listing_sends_contract_wallet_signature() {
+    // EIP-1271 signatures are verified by the wallet contract and can have any length
+    let signature = format!("0x{}", "ab".repeat(97));
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v2/orders/ethereum/seaport/listings"))
+        .and(body_partial_json(json!({ "signature": signature })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "order": order_json("0x01") })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let res = mock_client(server.uri()).post_listing(Chain::Ethereum, post_order_request(signature)).await.unwrap();
+    assert_eq!(res.order.order_hash.as_deref(), Some("0x01"));
+}
+
+#[tokio::test]
+async fn post_offer_sends_bulk_order_signature() {
+    let signature = format!("0x{}1b000002{}", "ab".repeat(64), "cd".repeat(32 * 2));
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v2/orders/ethereum/seaport/offers"))
+        .and(body_partial_json(json!({ "signature": signature })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "order": order_json("0x01") })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let res = mock_client(server.uri()).post_offer(Chain::Ethereum, post_order_request(signature)).await.unwrap();
+    assert_eq!(res.order.order_hash.as_deref(), Some("0x01"));
+}
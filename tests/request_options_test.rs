@@ -0,0 +1,31 @@
+use opensea_client_rs::{types::OpenSeaApiError, OpenSeaApiConfig, OpenSeaV2Client, RequestOptions};
+use reqwest::ClientBuilder;
+use serde_json::json;
+use std::time::Duration;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn per_request_timeout_overrides_client_timeout() {
+    let server = MockServer::start().await;
+    let stats = json!({
+        "total": { "volume": 1.0, "sales": 1, "average_price": 1.0, "num_owners": 1, "market_cap": 1.0, "floor_price": 1.0 },
+        "intervals": []
+    });
+    Mock::given(method("GET"))
+        .and(path("/v2/collections/slow/stats"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(stats).set_delay(Duration::from_millis(500)))
+        .mount(&server)
+        .await;
+
+    let cfg = OpenSeaApiConfig { base_url: Some(server.uri()), ..Default::default() };
+    let client = OpenSeaV2Client::from_builder(cfg, ClientBuilder::new().timeout(Duration::from_secs(10))).unwrap();
+
+    let opts = RequestOptions { timeout: Some(Duration::from_millis(50)) };
+    let res = client.get_collection_stats_opts("slow".to_string(), opts).await;
+    assert!(matches!(res, Err(OpenSeaApiError::Reqwest(e)) if e.is_timeout()));
+
+    assert!(client.get_collection_stats("slow".to_string()).await.is_ok());
+}
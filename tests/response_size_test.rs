@@ -0,0 +1,56 @@
+use opensea_client_rs::{
+    types::{api::AccountResponse, OpenSeaApiError},
+    OpenSeaApiConfig, OpenSeaV2Client,
+};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn account(bio: &str) -> serde_json::Value {
+    json!({
+        "address": "0x67d58520775af7848f3ee2adaa227435f5a91a04",
+        "username": "test",
+        "profile_image_url": "",
+        "banner_image_url": "",
+        "website": "",
+        "social_media_accounts": [],
+        "bio": bio,
+        "joined_date": "2021-01-01"
+    })
+}
+
+fn client(server: &MockServer, max_response_bytes: Option<usize>) -> OpenSeaV2Client {
+    OpenSeaV2Client::new(OpenSeaApiConfig { base_url: Some(server.uri()), max_response_bytes, ..Default::default() })
+}
+
+#[tokio::test]
+async fn rejects_responses_over_the_limit() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/accounts/test"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(account(&"a".repeat(10_000))))
+        .mount(&server)
+        .await;
+
+    let err = client(&server, Some(1024)).get_account("test".to_string()).await.unwrap_err();
+    assert!(matches!(err, OpenSeaApiError::Other(ref msg) if msg.contains("exceeds the limit of 1024 bytes")), "{err}");
+
+    let res: AccountResponse = client(&server, None).get_account("test".to_string()).await.unwrap();
+    assert_eq!(res.bio.unwrap().len(), 10_000);
+}
+
+#[tokio::test]
+async fn reads_responses_within_the_limit() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/accounts/test"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(account("short")))
+        .mount(&server)
+        .await;
+
+    let res = client(&server, Some(1024)).get_account("test".to_string()).await.unwrap();
+    assert_eq!(res.username.as_deref(), Some("test"));
+    assert_eq!(res.bio.as_deref(), Some("short"));
+}
@@ -1,7 +1,16 @@
 mod common;
-use common::test_client;
+use common::{mock_client, order_json, test_client};
 
-use opensea_client_rs::types::api::{OrderOpeningOption, RetrieveListingsRequest};
+use alloy_primitives::Address;
+use opensea_client_rs::types::{
+    api::{OrderOpeningOption, RetrieveListingsRequest},
+    Chain, OpenSeaApiError,
+};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param, query_param_is_missing},
+    Mock, MockServer, ResponseTemplate,
+};
 
 #[ignore]
 #[tokio::test]
@@ -15,3 +24,221 @@ async fn can_retrieve_listing() {
     assert_eq!(res.orders.len(), 3);
     assert!(res.next.is_some());
 }
+
+#[tokio::test]
+async fn can_retrieve_listings_on_other_chain() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/orders/matic/seaport/listings"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "next": null,
+            "previous": null,
+            "orders": [order_json("0x01")]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let res = client.retrieve_listings_on(Chain::Polygon, RetrieveListingsRequest::default()).await.unwrap();
+    assert_eq!(res.orders.len(), 1);
+    assert_eq!(res.chain, Some(Chain::Polygon));
+}
+
+#[tokio::test]
+async fn empty_listings_expose_the_chain_used() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/orders/ethereum/seaport/listings"))
+        .and(query_param("asset_contract_address", "0x0000000000000000000000000000000000000001"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "next": null, "previous": null, "orders": [] })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let req = RetrieveListingsRequest { asset_contract_address: Some(Address::with_last_byte(1)), ..Default::default() };
+    let res = client.retrieve_listings(req).await.unwrap();
+    assert!(res.orders.is_empty());
+    assert_eq!(res.chain.as_ref(), Some(client.chain()));
+    assert_eq!(client.chain(), &Chain::Ethereum);
+}
+
+#[tokio::test]
+async fn retrieve_listings_on_rejects_chain_of_other_network() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET")).respond_with(ResponseTemplate::new(500)).expect(0).mount(&server).await;
+
+    let client = mock_client(server.uri());
+    let err = client.retrieve_listings_on(Chain::Sepolia, RetrieveListingsRequest::default()).await.unwrap_err();
+    assert!(matches!(err, OpenSeaApiError::Other(_)));
+}
+
+#[tokio::test]
+async fn can_retrieve_all_listings_following_cursor() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/orders/ethereum/seaport/listings"))
+        .and(query_param_is_missing("cursor"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "next": "page2",
+            "previous": null,
+            "orders": [order_json("0x01"), order_json("0x02")]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/orders/ethereum/seaport/listings"))
+        .and(query_param("cursor", "page2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "next": null,
+            "previous": "page1",
+            "orders": [order_json("0x02"), order_json("0x03")]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let orders = client.retrieve_all_listings(RetrieveListingsRequest::default(), None).await.unwrap();
+    let hashes: Vec<_> = orders.into_iter().map(|order| order.order_hash.unwrap()).collect();
+    assert_eq!(hashes, vec!["0x01", "0x02", "0x03"]);
+}
+
+#[tokio::test]
+async fn retrieve_all_listings_partial_keeps_pages_before_error() {
+    let server = MockServer::start().await;
+    for (cursor, next, hashes) in [(None, "page2", ["0x01", "0x02"]), (Some("page2"), "page3", ["0x03", "0x04"])] {
+        let mock = Mock::given(method("GET")).and(path("/v2/orders/ethereum/seaport/listings"));
+        let mock = match cursor {
+            Some(cursor) => mock.and(query_param("cursor", cursor)),
+            None => mock.and(query_param_is_missing("cursor")),
+        };
+        mock.respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "next": next,
+            "previous": null,
+            "orders": hashes.map(order_json)
+        })))
+        .expect(2)
+        .mount(&server)
+        .await;
+    }
+    Mock::given(method("GET"))
+        .and(path("/v2/orders/ethereum/seaport/listings"))
+        .and(query_param("cursor", "page3"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    // Each page is requested twice, by the partial and the failing retrieval
+    let client = mock_client(server.uri());
+    let res = client.retrieve_all_listings_partial(RetrieveListingsRequest::default(), None).await;
+    let hashes: Vec<_> = res.orders.into_iter().map(|order| order.order_hash.unwrap()).collect();
+    assert_eq!(hashes, vec!["0x01", "0x02", "0x03", "0x04"]);
+    assert_eq!(res.pages_fetched, 2);
+    let err = res.error.unwrap();
+    assert!(matches!(err.without_chain(), OpenSeaApiError::UnexpectedStatus { status, .. } if *status == 500));
+    assert!(matches!(err, OpenSeaApiError::OnChain { chain: Chain::Ethereum, .. }));
+
+    assert!(client.retrieve_all_listings(RetrieveListingsRequest::default(), None).await.is_err());
+
+    let res = client.retrieve_all_listings_partial(RetrieveListingsRequest::default(), Some(0)).await;
+    assert!(res.orders.is_empty() && res.error.is_none());
+    assert_eq!(res.pages_fetched, 0);
+}
+
+#[tokio::test]
+async fn can_retrieve_listings_for_makers() {
+    let server = MockServer::start().await;
+    let makers = [Address::with_last_byte(1), Address::with_last_byte(2)];
+    for (maker, hashes) in makers.iter().zip([["0x01", "0x02"], ["0x02", "0x03"]]) {
+        Mock::given(method("GET"))
+            .and(path("/v2/orders/ethereum/seaport/listings"))
+            .and(query_param("maker", maker.to_string().to_lowercase()))
+            .and(query_param("limit", "50"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "next": null,
+                "previous": null,
+                "orders": hashes.iter().map(|hash| order_json(hash)).collect::<Vec<_>>()
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let client = mock_client(server.uri());
+    let req = RetrieveListingsRequest { limit: Some(50), ..Default::default() };
+    let orders = client.retrieve_listings_for_makers(makers.to_vec(), req).await.unwrap();
+    let hashes: Vec<_> = orders.iter().map(|order| order.order_hash.as_deref().unwrap()).collect();
+    assert_eq!(hashes, vec!["0x01", "0x02", "0x03"]);
+}
+
+#[tokio::test]
+async fn splits_long_token_ids_queries() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/orders/ethereum/seaport/listings"))
+        .and(query_param("token_ids", "1000"))
+        .and(query_param_is_missing("cursor"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "next": "page2",
+            "previous": null,
+            "orders": [order_json("0x01"), order_json("0x02")]
+        })))
+        .expect(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/orders/ethereum/seaport/listings"))
+        .and(query_param("token_ids", "1000"))
+        .and(query_param("cursor", "page2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "next": null,
+            "previous": "page1",
+            "orders": [order_json("0x04")]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/orders/ethereum/seaport/listings"))
+        .and(query_param("token_ids", "1299"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "next": null,
+            "previous": null,
+            "orders": [order_json("0x02"), order_json("0x03")]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/orders/ethereum/seaport/listings"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "next": null, "previous": null, "orders": [] })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // 300 token ids of 15 characters each exceed the query length limit of 2000 characters
+    let token_ids: Vec<String> = (1000..1300).map(|id| id.to_string()).collect();
+    let req = RetrieveListingsRequest { asset_contract_address: Some(Address::with_last_byte(1)), token_ids, ..Default::default() };
+    let client = mock_client(server.uri());
+    let err = client.retrieve_listings(req.clone()).await.unwrap_err();
+    assert!(matches!(err.without_chain(), OpenSeaApiError::Other(msg) if msg.contains("retrieve_all_listings")), "{err}");
+    assert!(server.received_requests().await.unwrap().is_empty());
+
+    let orders = client.retrieve_all_listings(req.clone(), None).await.unwrap();
+    let hashes: Vec<_> = orders.iter().map(|order| order.order_hash.as_deref().unwrap()).collect();
+    assert_eq!(hashes, vec!["0x01", "0x02", "0x04", "0x03"]);
+
+    // The page limit applies across the chunks
+    let res = client.retrieve_all_listings_partial(req, Some(1)).await;
+    let hashes: Vec<_> = res.orders.iter().map(|order| order.order_hash.as_deref().unwrap()).collect();
+    assert_eq!(hashes, vec!["0x01", "0x02"]);
+    assert_eq!(res.pages_fetched, 1);
+
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 5);
+    assert!(requests.iter().all(|req| req.url.query().unwrap().len() <= 2000));
+}
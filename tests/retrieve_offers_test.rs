@@ -0,0 +1,165 @@
+mod common;
+use common::{mock_client, order_json};
+
+use alloy_primitives::Address;
+use futures::{StreamExt, TryStreamExt};
+use opensea_client_rs::types::{
+    api::{nfts::GetNftsRequest, RetrieveOffersRequest},
+    Chain, OpenSeaApiError,
+};
+use serde_json::json;
+use std::str::FromStr;
+use wiremock::{
+    matchers::{method, path, query_param, query_param_is_missing},
+    Mock, MockServer, ResponseTemplate,
+};
+
+async fn mount_offer_pages(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/v2/orders/ethereum/seaport/offers"))
+        .and(query_param_is_missing("cursor"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "next": "page2",
+            "previous": null,
+            "orders": [order_json("0x01"), order_json("0x02")]
+        })))
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/orders/ethereum/seaport/offers"))
+        .and(query_param("cursor", "page2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "next": "page3",
+            "previous": "page1",
+            "orders": [order_json("0x02"), order_json("0x03")]
+        })))
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/orders/ethereum/seaport/offers"))
+        .and(query_param("cursor", "page3"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "next": null,
+            "previous": "page2",
+            "orders": [order_json("0x04")]
+        })))
+        .mount(server)
+        .await;
+}
+
+fn order_hashes(orders: Vec<opensea_client_rs::types::api::orders::Order>) -> Vec<String> {
+    orders.into_iter().map(|order| order.order_hash.unwrap()).collect()
+}
+
+#[tokio::test]
+async fn can_retrieve_all_offers() {
+    let server = MockServer::start().await;
+    mount_offer_pages(&server).await;
+
+    let client = mock_client(server.uri());
+    let orders = client.retrieve_all_offers(RetrieveOffersRequest::default(), None).await.unwrap();
+    assert_eq!(order_hashes(orders), vec!["0x01", "0x02", "0x03", "0x04"]);
+}
+
+#[tokio::test]
+async fn retrieve_all_offers_stops_at_max_pages() {
+    let server = MockServer::start().await;
+    mount_offer_pages(&server).await;
+
+    let client = mock_client(server.uri());
+    let orders = client.retrieve_all_offers(RetrieveOffersRequest::default(), Some(2)).await.unwrap();
+    assert_eq!(order_hashes(orders), vec!["0x01", "0x02", "0x03"]);
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+
+    let orders = client.retrieve_all_offers(RetrieveOffersRequest::default(), Some(0)).await.unwrap();
+    assert!(orders.is_empty());
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn can_stream_offers_from_cursor() {
+    let server = MockServer::start().await;
+    mount_offer_pages(&server).await;
+
+    let client = mock_client(server.uri());
+    let req = RetrieveOffersRequest::builder().limit(2).cursor("page2").build().unwrap();
+    let orders: Vec<_> = client.retrieve_offers_stream(req).try_collect().await.unwrap();
+    assert_eq!(order_hashes(orders), vec!["0x02", "0x03", "0x04"]);
+
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 2);
+    assert!(requests.iter().all(|req| req.url.query_pairs().any(|(k, v)| k == "limit" && v == "2")));
+}
+
+#[tokio::test]
+async fn offers_stream_requests_pages_lazily() {
+    let server = MockServer::start().await;
+    mount_offer_pages(&server).await;
+
+    let client = mock_client(server.uri());
+    let orders: Vec<_> = client.retrieve_offers_stream(RetrieveOffersRequest::default()).take(2).try_collect().await.unwrap();
+    assert_eq!(order_hashes(orders), vec!["0x01", "0x02"]);
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn can_retrieve_offers() {
+    let server = MockServer::start().await;
+    let body = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/resources/response_get_offers.json")).unwrap();
+    Mock::given(method("GET"))
+        .and(path("/v2/orders/ethereum/seaport/offers"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let res = client.retrieve_offers(RetrieveOffersRequest::default()).await.unwrap();
+    assert_eq!(order_hashes(res.orders), vec!["0x6e3c5d1f5a3e4b2f1b0b5e9c3a1d7f4e8c2b6a9d0e1f2a3b4c5d6e7f8a9b0c1d"]);
+}
+
+#[tokio::test]
+async fn can_get_account_offers() {
+    let server = MockServer::start().await;
+    let maker = Address::from_str("0x67d58520775af7848f3ee2adaa227435f5a91a04").unwrap();
+    let body = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/resources/response_get_offers.json")).unwrap();
+    Mock::given(method("GET"))
+        .and(path("/v2/orders/ethereum/seaport/offers"))
+        .and(query_param("maker", "0x67d58520775af7848f3ee2adaa227435f5a91a04"))
+        .and(query_param("limit", "10"))
+        .and(query_param("cursor", "page2"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let req = GetNftsRequest { limit: Some(10), next: Some("page2".into()), ..Default::default() };
+    let res = client.get_account_offers(Chain::Ethereum, maker, req).await.unwrap();
+    assert_eq!(res.orders.len(), 1);
+    assert!(res.orders[0].order_hash.is_some());
+}
+
+#[tokio::test]
+async fn offers_expose_the_chain_used() {
+    let server = MockServer::start().await;
+    mount_offer_pages(&server).await;
+
+    let client = mock_client(server.uri());
+    let res = client.retrieve_offers(RetrieveOffersRequest::default()).await.unwrap();
+    assert_eq!(res.chain, Some(Chain::Ethereum));
+}
+
+#[tokio::test]
+async fn offer_errors_name_the_chain_used() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/orders/ethereum/seaport/offers"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+
+    let client = mock_client(server.uri());
+    let err = client.retrieve_all_offers(RetrieveOffersRequest::default(), None).await.unwrap_err();
+    assert!(err.to_string().ends_with("(chain ethereum)"), "{err}");
+    assert!(matches!(err.without_chain(), OpenSeaApiError::UnexpectedStatus { .. }));
+}
@@ -0,0 +1,121 @@
+mod common;
+use common::order_json;
+
+use opensea_client_rs::{
+    types::{
+        api::{orders::Order, PostOrderRequest, RetrieveListingsRequest},
+        Chain, OpenSeaApiError,
+    },
+    OpenSeaApiConfig, OpenSeaV2Client,
+};
+use serde_json::json;
+use std::time::Duration;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn retry_client(base_url: String) -> OpenSeaV2Client {
+    OpenSeaV2Client::new(OpenSeaApiConfig { base_url: Some(base_url), max_retries: 2, ..Default::default() })
+}
+
+fn rate_limited() -> ResponseTemplate {
+    ResponseTemplate::new(429).insert_header("Retry-After", "0")
+}
+
+#[tokio::test]
+async fn retrieve_listings_is_retried_on_rate_limit() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/orders/ethereum/seaport/listings"))
+        .respond_with(rate_limited())
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/orders/ethereum/seaport/listings"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "next": null,
+            "previous": null,
+            "orders": [order_json("0x01")]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let res = retry_client(server.uri()).retrieve_listings(RetrieveListingsRequest::default()).await.unwrap();
+    assert_eq!(res.orders.len(), 1);
+}
+
+#[tokio::test]
+async fn post_listing_is_not_retried_on_rate_limit() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v2/orders/ethereum/seaport/listings"))
+        .respond_with(rate_limited())
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let order: Order = serde_json::from_value(order_json("0x01")).unwrap();
+    let req = PostOrderRequest {
+        parameters: order.protocol_data.parameters,
+        signature: format!("0x{}1b", "ab".repeat(64)),
+        protocol_address: "0x0000000000000068f116a894984e2db1123eb395".parse().unwrap(),
+    };
+
+    let res = retry_client(server.uri()).post_listing(Chain::Ethereum, req).await;
+    assert!(matches!(res, Err(OpenSeaApiError::RateLimited { .. })));
+}
+
+#[tokio::test]
+async fn shutdown_cancels_request_waiting_for_retry() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/orders/ethereum/seaport/listings"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "60"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = retry_client(server.uri());
+    let pending = tokio::spawn({
+        let client = client.clone();
+        async move { client.retrieve_listings(RetrieveListingsRequest::default()).await }
+    });
+    while server.received_requests().await.unwrap().is_empty() {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    client.shutdown();
+
+    let res = tokio::time::timeout(Duration::from_secs(5), pending).await.expect("request still backing off").unwrap();
+    assert!(matches!(res, Err(OpenSeaApiError::Cancelled)));
+    assert!(matches!(client.retrieve_listings(RetrieveListingsRequest::default()).await, Err(OpenSeaApiError::Cancelled)));
+}
+
+#[tokio::test]
+async fn long_retry_after_is_returned_instead_of_waiting() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v2/orders/ethereum/seaport/listings"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "86400"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cfg = OpenSeaApiConfig {
+        base_url: Some(server.uri()),
+        max_retries: 2,
+        max_retry_delay: Some(Duration::from_secs(1)),
+        ..Default::default()
+    };
+    let client = OpenSeaV2Client::new(cfg);
+    let err = tokio::time::timeout(Duration::from_secs(5), client.retrieve_listings(RetrieveListingsRequest::default()))
+        .await
+        .unwrap()
+        .unwrap_err();
+    assert!(
+        matches!(err.without_chain(), OpenSeaApiError::RateLimited { retry_after: Some(delay) } if *delay == Duration::from_secs(86400))
+    );
+}